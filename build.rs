@@ -1,10 +1,54 @@
 use sport::item_list::ItemList;
 use std::fs;
-use std::path::Path;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+const SW_INPUTS: &[&str] = &["Cargo.toml", "src", "sport/src", "assets", "public"];
+/// Written by this script, watching them would rerun it on every build
+const GENERATED: &[&str] = &["assets/items.json", "public/sw.js"];
+
+fn files(path: &Path) -> Vec<PathBuf> {
+    if path.is_file() {
+        return vec![path.to_path_buf()];
+    }
+    let mut entries = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    entries.sort();
+    entries.iter().flat_map(|entry| files(entry)).collect()
+}
+
+/// The service worker cache is versioned by a hash of everything that ends up in the bundle
+fn service_worker() {
+    let mut hasher = DefaultHasher::new();
+    for input in SW_INPUTS {
+        for file in files(Path::new(input)) {
+            if GENERATED
+                .iter()
+                .any(|generated| file == Path::new(generated))
+            {
+                continue;
+            }
+            println!("cargo::rerun-if-changed={}", file.display());
+            file.hash(&mut hasher);
+            fs::read(&file).unwrap_or_default().hash(&mut hasher);
+        }
+    }
+    println!("cargo::rerun-if-changed=sw.template.js");
+    let template = fs::read_to_string("sw.template.js").unwrap();
+    let version = format!("{:016x}", hasher.finish());
+    fs::write("public/sw.js", template.replace("{{VERSION}}", &version)).unwrap();
+}
 
 fn main() {
     let dest_path = Path::new("./assets/items.json");
     let items_str = ItemList::export_json();
     fs::write(dest_path, items_str).unwrap();
+    service_worker();
     println!("cargo::rerun-if-changed=build.rs");
 }
@@ -0,0 +1,178 @@
+use crate::runner::{Command, Runner, TimerStatus};
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use sport::defaults::{SEQUENCES, TEMPLATES, find};
+use sport::errors::ErrorKind;
+use sport::item::Item;
+use sport::item_list::ItemList;
+use sport::sequence::Sequence;
+use sport::tag::Tag;
+use sport::template::{ParameterKind, Template, Values};
+
+pub const DEFAULT_ADDRESS: &str = "0.0.0.0:8090";
+
+pub struct ApiError(StatusCode, String);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(serde_json::json!({ "error": self.1 }))).into_response()
+    }
+}
+
+impl From<ErrorKind> for ApiError {
+    fn from(err: ErrorKind) -> Self {
+        Self(StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+    }
+}
+
+/// Template parameters, missing ones take the template defaults
+#[derive(Debug, Default, Deserialize)]
+pub struct Parameters {
+    rounds: Option<u64>,
+    work: Option<u64>,
+    rest: Option<u64>,
+}
+
+impl Parameters {
+    fn values(&self) -> Values {
+        [
+            (ParameterKind::Rounds, self.rounds),
+            (ParameterKind::Work, self.work),
+            (ParameterKind::Rest, self.rest),
+        ]
+        .into_iter()
+        .filter_map(|(kind, value)| Some((kind, value?)))
+        .collect()
+    }
+}
+
+async fn sequences() -> Json<&'static [Sequence]> {
+    Json(SEQUENCES.as_slice())
+}
+
+async fn sequence(
+    Path(slug): Path<String>,
+    Query(parameters): Query<Parameters>,
+) -> Result<Json<Sequence>, ApiError> {
+    find(&slug, &parameters.values())
+        .map(Json)
+        .ok_or_else(|| ApiError(StatusCode::NOT_FOUND, format!("unknown sequence {slug}")))
+}
+
+async fn templates() -> Json<&'static [Template]> {
+    Json(TEMPLATES.as_slice())
+}
+
+async fn items() -> Json<Vec<Item>> {
+    Json(ItemList::items())
+}
+
+async fn tags() -> Json<Vec<Tag>> {
+    Json(ItemList::tags())
+}
+
+async fn status(State(runner): State<Runner>) -> Result<Json<TimerStatus>, ApiError> {
+    Ok(Json(runner.send(Command::Status).await?))
+}
+
+async fn load(
+    State(runner): State<Runner>,
+    Path(slug): Path<String>,
+    Query(parameters): Query<Parameters>,
+) -> Result<Json<TimerStatus>, ApiError> {
+    let sequence = find(&slug, &parameters.values())
+        .ok_or_else(|| ApiError(StatusCode::NOT_FOUND, format!("unknown sequence {slug}")))?;
+    Ok(Json(runner.send(Command::Load(Box::new(sequence))).await?))
+}
+
+async fn control(
+    State(runner): State<Runner>,
+    Path(action): Path<String>,
+) -> Result<Json<TimerStatus>, ApiError> {
+    let command = Command::parse(&action)
+        .filter(|command| !matches!(command, Command::Load(_) | Command::Status))
+        .ok_or_else(|| ApiError(StatusCode::NOT_FOUND, format!("unknown action {action}")))?;
+    Ok(Json(runner.send(command).await?))
+}
+
+/// REST endpoints over the catalog and a server side timer
+pub fn router(runner: Runner) -> Router {
+    Router::new()
+        .route("/sequences", get(sequences))
+        .route("/sequences/{slug}", get(sequence))
+        .route("/templates", get(templates))
+        .route("/items", get(items))
+        .route("/tags", get(tags))
+        .route("/timer", get(status))
+        .route("/timer/load/{slug}", post(load))
+        .route("/timer/{action}", post(control))
+        .with_state(runner)
+}
+
+#[tokio::test]
+async fn api_tests() {
+    use axum::body::Body;
+    use axum::http::Request;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    let app = router(Runner::spawn(|_| {}));
+    let call = |method: &str, uri: &str| {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            (
+                status,
+                serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            )
+        }
+    };
+
+    let (status, sequences) = call("GET", "/sequences").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(sequences.as_array().unwrap().len(), SEQUENCES.len());
+    let (_, items) = call("GET", "/items").await;
+    assert_eq!(items.as_array().unwrap().len(), ItemList::items().len());
+    let (_, tags) = call("GET", "/tags").await;
+    assert_eq!(tags.as_array().unwrap().len(), ItemList::tags().len());
+    let (status, _) = call("GET", "/sequences/unknown").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let template = TEMPLATES
+        .iter()
+        .find(|t| t.slug() == "boxing-rounds")
+        .unwrap();
+    let (status, sequence) = call("GET", "/sequences/boxing-rounds?rounds=2").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        sequence["name"],
+        template
+            .instantiate(&Values::from([(ParameterKind::Rounds, 2)]))
+            .name()
+    );
+
+    let slug = SEQUENCES.first().unwrap().slug();
+    let (status, timer) = call("POST", &format!("/timer/load/{slug}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(timer["slug"], slug);
+    assert_eq!(timer["running"], false);
+    let (_, timer) = call("POST", "/timer/start").await;
+    assert_eq!(timer["running"], true);
+    let (_, timer) = call("POST", "/timer/next").await;
+    assert_eq!(timer["index"], 0);
+    let (_, timer) = call("GET", "/timer").await;
+    assert_eq!(timer["index"], 0);
+    let (status, _) = call("POST", "/timer/jump").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,22 @@
+//! HTTP JSON API over the catalog and a server side timer
+//!
+//! Usage: `api [address]`, listens on 0.0.0.0:8090 by default.
+use server::api::{DEFAULT_ADDRESS, router};
+use server::runner::Runner;
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()),
+        )
+        .init();
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let listener = TcpListener::bind(&address).await?;
+    tracing::info!("api listening on http://{}", listener.local_addr()?);
+    axum::serve(listener, router(Runner::spawn(|_| {}))).await?;
+    Ok(())
+}
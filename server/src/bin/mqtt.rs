@@ -0,0 +1,26 @@
+//! Publish timer events to an MQTT broker for gym automation
+//!
+//! Usage: `mqtt [host:port] [topic]`, connects to localhost:1883 on the sport/timer topic by default.
+//! Events are published as JSON on `{topic}/events`, text commands such as `load boxing-rounds`,
+//! `start`, `pause` or `next` are read from `{topic}/command`.
+use rumqttc::MqttOptions;
+use server::mqtt::{DEFAULT_BROKER, DEFAULT_TOPIC, run};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()),
+        )
+        .init();
+    let mut args = std::env::args().skip(1);
+    let broker = args.next().unwrap_or_else(|| DEFAULT_BROKER.to_string());
+    let topic = args.next().unwrap_or_else(|| DEFAULT_TOPIC.to_string());
+    let (host, port) = broker
+        .rsplit_once(':')
+        .ok_or_else(|| format!("invalid broker address {broker}, expected host:port"))?;
+    let options = MqttOptions::new("sport-timer", host, port.parse()?);
+    tracing::info!("publishing on mqtt://{broker}/{topic}/events");
+    run(options, &topic).await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+//! WebSocket relay between a controller device and its followers on the LAN
+//!
+//! Usage: `relay [address]`, listens on 0.0.0.0:9001 by default.
+use server::relay::{DEFAULT_ADDRESS, Rooms, serve};
+use tokio::net::TcpListener;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()),
+        )
+        .init();
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let listener = TcpListener::bind(&address).await?;
+    tracing::info!("relay listening on ws://{}", listener.local_addr()?);
+    serve(listener, Rooms::default()).await?;
+    Ok(())
+}
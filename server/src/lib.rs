@@ -0,0 +1,4 @@
+pub mod api;
+pub mod mqtt;
+pub mod relay;
+pub mod runner;
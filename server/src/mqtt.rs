@@ -0,0 +1,284 @@
+use crate::runner::{Command, Runner, TimerStatus};
+use rumqttc::{AsyncClient, MqttOptions, Packet, QoS};
+use serde::Serialize;
+use sport::errors::ErrorKind;
+use sport::timer::Phase;
+
+pub const DEFAULT_BROKER: &str = "localhost:1883";
+pub const DEFAULT_TOPIC: &str = "sport/timer";
+
+/// Seconds announced before the end of a workout
+pub const COUNTDOWN: u64 = 3;
+
+/// First wait after a broker error, doubled on each failure
+const RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Payload published as JSON on `{topic}/events`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// A workout starts, `phase` tells work from rest
+    Transition {
+        sequence: String,
+        index: usize,
+        workouts: usize,
+        label: String,
+        left: u64,
+        phase: Phase,
+    },
+    /// One of the last seconds of a workout
+    Countdown { label: String, left: u64 },
+    /// The last workout ran down to zero
+    Finish { sequence: String },
+}
+
+impl Event {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("events are always serializable")
+    }
+}
+
+/// Turns successive timer statuses into events
+#[derive(Clone, Debug, Default)]
+pub struct Events {
+    last: Option<TimerStatus>,
+}
+
+impl Events {
+    pub fn update(&mut self, status: TimerStatus) -> Vec<Event> {
+        let mut events = Vec::new();
+        let last = self.last.as_ref();
+        let moved = last.is_none_or(|last| last.slug != status.slug || last.index != status.index);
+        if let Some(index) = status.index
+            && moved
+        {
+            events.push(Event::Transition {
+                sequence: status.sequence.clone(),
+                index,
+                workouts: status.workouts,
+                label: status.label.clone(),
+                left: status.left,
+                phase: status.phase,
+            });
+        }
+        if status.running
+            && status.index.is_some()
+            && (1..=COUNTDOWN).contains(&status.left)
+            && (moved || last.is_some_and(|last| last.left != status.left))
+        {
+            events.push(Event::Countdown {
+                label: status.label.clone(),
+                left: status.left,
+            });
+        }
+        if status.phase.is_finished() && last.is_none_or(|last| !last.phase.is_finished()) {
+            events.push(Event::Finish {
+                sequence: status.sequence.clone(),
+            });
+        }
+        self.last = Some(status);
+        events
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum MqttError {
+    #[error("mqtt client error: {0}")]
+    Client(#[from] rumqttc::ClientError),
+    #[error("mqtt connection error: {0}")]
+    Connection(#[from] rumqttc::ConnectionError),
+    #[error("timer error: {0}")]
+    Timer(#[from] ErrorKind),
+}
+
+/// Publish timer events on `{topic}/events` and obey text commands from `{topic}/command`
+pub async fn run(options: MqttOptions, topic: &str) -> Result<(), MqttError> {
+    let (client, mut eventloop) = AsyncClient::new(options, 64);
+    let command_topic = format!("{topic}/command");
+    let subscriber = client.clone();
+
+    let events_topic = format!("{topic}/events");
+    let mut events = Events::default();
+    let runner = Runner::spawn(move |timer| {
+        for event in events.update(TimerStatus::from(timer)) {
+            if let Err(err) =
+                client.try_publish(&events_topic, QoS::AtLeastOnce, false, event.to_json())
+            {
+                tracing::warn!("cannot publish {event:?}: {err}");
+            }
+        }
+    });
+
+    let mut backoff = RECONNECT_DELAY;
+    loop {
+        let event = match eventloop.poll().await {
+            Ok(event) => {
+                backoff = RECONNECT_DELAY;
+                event
+            }
+            // the next poll reconnects
+            Err(err) => {
+                tracing::warn!("mqtt connection lost, retrying in {backoff:?}: {err}");
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_RECONNECT_DELAY);
+                continue;
+            }
+        };
+        match event {
+            // subscriptions do not survive a clean session
+            rumqttc::Event::Incoming(Packet::ConnAck(_)) => {
+                subscriber
+                    .subscribe(command_topic.as_str(), QoS::AtLeastOnce)
+                    .await?;
+            }
+            rumqttc::Event::Incoming(Packet::Publish(publish))
+                if publish.topic == command_topic =>
+            {
+                let text = String::from_utf8_lossy(&publish.payload);
+                match Command::parse(&text) {
+                    Some(command) => {
+                        runner.send(command).await?;
+                    }
+                    None => tracing::warn!("unknown command {text}"),
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn events_tests() {
+    let status = |index, left, running, phase| TimerStatus {
+        sequence: "Boxing".to_string(),
+        slug: "boxing".to_string(),
+        label: "Boxing round".to_string(),
+        index,
+        workouts: 2,
+        left,
+        running,
+        phase,
+    };
+    let mut events = Events::default();
+    assert!(
+        events
+            .update(status(None, 10, true, Phase::Preparing))
+            .is_empty()
+    );
+    assert_eq!(
+        events.update(status(Some(0), 4, true, Phase::Working)),
+        vec![Event::Transition {
+            sequence: "Boxing".to_string(),
+            index: 0,
+            workouts: 2,
+            label: "Boxing round".to_string(),
+            left: 4,
+            phase: Phase::Working,
+        }]
+    );
+    let countdown = |left| Event::Countdown {
+        label: "Boxing round".to_string(),
+        left,
+    };
+    assert_eq!(
+        events.update(status(Some(0), 3, true, Phase::Working)),
+        vec![countdown(3)]
+    );
+    // a paused timer stays silent
+    assert!(
+        events
+            .update(status(Some(0), 2, false, Phase::Working))
+            .is_empty()
+    );
+    assert_eq!(
+        events.update(status(Some(0), 1, true, Phase::Working)),
+        vec![countdown(1)]
+    );
+    assert!(
+        events
+            .update(status(Some(0), 1, true, Phase::Working))
+            .is_empty()
+    );
+    assert_eq!(
+        events
+            .update(status(Some(1), 0, false, Phase::Finished))
+            .len(),
+        2
+    );
+    assert!(
+        events
+            .update(status(Some(1), 0, false, Phase::Finished))
+            .is_empty()
+    );
+
+    assert_eq!(
+        countdown(2).to_json(),
+        r#"{"event":"countdown","label":"Boxing round","left":2}"#
+    );
+}
+
+#[tokio::test]
+async fn mqtt_tests() {
+    use bytes::BytesMut;
+    use rumqttc::{ConnAck, ConnectReturnCode, Publish, SubAck, SubscribeReasonCode};
+    use sport::defaults::SEQUENCES;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    // a broker stand-in serving a single client
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let client = tokio::spawn(run(MqttOptions::new("gym", "127.0.0.1", port), "gym"));
+    let (mut stream, _) = listener.accept().await.unwrap();
+
+    let slug = SEQUENCES.first().unwrap().slug();
+    let mut buffer = BytesMut::new();
+    let mut commands = vec![format!("load {slug}"), "start".into(), "next".into()].into_iter();
+    let event = loop {
+        let packet = match Packet::read(&mut buffer, 1 << 20) {
+            Ok(packet) => packet,
+            Err(rumqttc::Error::InsufficientBytes(_)) => {
+                assert_ne!(stream.read_buf(&mut buffer).await.unwrap(), 0);
+                continue;
+            }
+            Err(err) => panic!("{err}"),
+        };
+        let mut replies = Vec::new();
+        match packet {
+            Packet::Connect(..) => {
+                replies.push(Packet::ConnAck(ConnAck::new(
+                    ConnectReturnCode::Success,
+                    false,
+                )));
+            }
+            Packet::Subscribe(subscribe) => {
+                assert_eq!(subscribe.filters[0].path, "gym/command");
+                replies.push(Packet::SubAck(SubAck::new(
+                    subscribe.pkid,
+                    vec![SubscribeReasonCode::Success(QoS::AtMostOnce)],
+                )));
+                replies.extend(commands.by_ref().map(|command| {
+                    Packet::Publish(Publish::new("gym/command", QoS::AtMostOnce, command))
+                }));
+            }
+            Packet::Publish(publish) => {
+                assert_eq!(publish.topic, "gym/events");
+                break serde_json::from_slice::<serde_json::Value>(&publish.payload).unwrap();
+            }
+            _ => {}
+        }
+        let mut bytes = BytesMut::new();
+        for reply in replies {
+            reply.write(&mut bytes, 1 << 20).unwrap();
+        }
+        stream.write_all(&bytes).await.unwrap();
+    };
+    assert_eq!(event["event"], "transition");
+    assert_eq!(event["index"], 0);
+    assert_eq!(
+        event["label"],
+        SEQUENCES.first().unwrap().first().unwrap().item().name()
+    );
+    client.abort();
+}
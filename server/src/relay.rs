@@ -0,0 +1,240 @@
+use futures_util::{SinkExt, StreamExt};
+use sport::protocol::{Message, Role};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use tokio_tungstenite::tungstenite;
+
+pub const DEFAULT_ADDRESS: &str = "0.0.0.0:9001";
+
+type ClientId = usize;
+
+struct Client {
+    role: Role,
+    sender: UnboundedSender<String>,
+}
+
+#[derive(Default)]
+struct Room {
+    clients: HashMap<ClientId, Client>,
+    /// Last state sent by a controller, replayed to late followers
+    state: Option<String>,
+}
+
+/// Clients grouped by room
+#[derive(Clone, Default)]
+pub struct Rooms {
+    rooms: Arc<Mutex<HashMap<String, Room>>>,
+    next_id: Arc<Mutex<ClientId>>,
+}
+
+impl Rooms {
+    fn join(&self, room: &str, role: Role, sender: UnboundedSender<String>) -> ClientId {
+        let id = {
+            let mut next_id = self.next_id.lock().expect("poisoned lock");
+            *next_id += 1;
+            *next_id
+        };
+        let mut rooms = self.rooms.lock().expect("poisoned lock");
+        let room = rooms.entry(room.to_string()).or_default();
+        if role == Role::Follower
+            && let Some(state) = &room.state
+        {
+            let _ = sender.send(state.clone());
+        }
+        room.clients.insert(id, Client { role, sender });
+        id
+    }
+    fn leave(&self, room: &str, id: ClientId) {
+        let mut rooms = self.rooms.lock().expect("poisoned lock");
+        if let Some(joined) = rooms.get_mut(room) {
+            joined.clients.remove(&id);
+            if joined.clients.is_empty() {
+                rooms.remove(room);
+            }
+        }
+    }
+    /// Forward a controller message to the rest of the room
+    fn broadcast(&self, room: &str, from: ClientId, text: &str) {
+        let mut rooms = self.rooms.lock().expect("poisoned lock");
+        let Some(room) = rooms.get_mut(room) else {
+            return;
+        };
+        if room
+            .clients
+            .get(&from)
+            .is_none_or(|client| client.role != Role::Controller)
+        {
+            return;
+        }
+        if matches!(Message::from_json(text), Ok(Message::State(_))) {
+            room.state = Some(text.to_string());
+        }
+        for (id, client) in &room.clients {
+            if *id != from {
+                let _ = client.sender.send(text.to_string());
+            }
+        }
+    }
+    pub fn len(&self, room: &str) -> usize {
+        self.rooms
+            .lock()
+            .expect("poisoned lock")
+            .get(room)
+            .map_or(0, |room| room.clients.len())
+    }
+    pub fn is_empty(&self, room: &str) -> bool {
+        self.len(room) == 0
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RelayError {
+    #[error("websocket error: {0}")]
+    WebSocket(#[from] tungstenite::Error),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("the first message must be a join")]
+    NotJoined,
+}
+
+async fn handle(rooms: Rooms, stream: TcpStream) -> Result<(), RelayError> {
+    let websocket = tokio_tungstenite::accept_async(stream).await?;
+    let (mut outgoing, mut incoming) = websocket.split();
+
+    let (room, role) = loop {
+        match incoming.next().await {
+            Some(Ok(tungstenite::Message::Text(text))) => match Message::from_json(&text) {
+                Ok(Message::Join { room, role }) => break (room, role),
+                _ => return Err(RelayError::NotJoined),
+            },
+            Some(Ok(tungstenite::Message::Ping(_) | tungstenite::Message::Pong(_))) => {}
+            Some(Err(err)) => return Err(err.into()),
+            _ => return Err(RelayError::NotJoined),
+        }
+    };
+    let (sender, mut receiver) = unbounded_channel();
+    let id = rooms.join(&room, role, sender);
+    tracing::info!("client {id} joined {room} as {role:?}");
+
+    let forward = async {
+        while let Some(text) = receiver.recv().await {
+            if outgoing
+                .send(tungstenite::Message::text(text))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    };
+    let relay = async {
+        while let Some(Ok(message)) = incoming.next().await {
+            match message {
+                tungstenite::Message::Text(text) => rooms.broadcast(&room, id, &text),
+                tungstenite::Message::Close(_) => break,
+                _ => {}
+            }
+        }
+    };
+    tokio::select! {
+        () = forward => {},
+        () = relay => {},
+    }
+    rooms.leave(&room, id);
+    tracing::info!("client {id} left {room}");
+    Ok(())
+}
+
+/// Accept websocket clients forever
+pub async fn serve(listener: TcpListener, rooms: Rooms) -> Result<(), RelayError> {
+    loop {
+        let (stream, address) = listener.accept().await?;
+        let rooms = rooms.clone();
+        tokio::spawn(async move {
+            if let Err(err) = handle(rooms, stream).await {
+                tracing::warn!("client {address}: {err}");
+            }
+        });
+    }
+}
+
+#[tokio::test]
+async fn relay_tests() {
+    use sport::defaults::SEQUENCES;
+    use sport::protocol::TimerState;
+    use sport::sound::Sound;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let rooms = Rooms::default();
+    tokio::spawn(serve(listener, rooms.clone()));
+
+    let join = |room: &str, role| {
+        tungstenite::Message::text(
+            Message::Join {
+                room: room.to_string(),
+                role,
+            }
+            .to_json(),
+        )
+    };
+    let (mut controller, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+    let (mut follower, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+    let (mut stranger, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+    controller
+        .send(join("gym", Role::Controller))
+        .await
+        .unwrap();
+    follower.send(join("gym", Role::Follower)).await.unwrap();
+    stranger.send(join("home", Role::Follower)).await.unwrap();
+    while rooms.len("gym") < 2 || rooms.is_empty("home") {
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+
+    let state = Message::State(TimerState {
+        sequence: SEQUENCES.first().unwrap().clone(),
+        index: None,
+        left: std::time::Duration::from_secs(10),
+        running: false,
+        phase: sport::timer::Phase::Preparing,
+    });
+    // followers cannot drive the room
+    follower
+        .send(tungstenite::Message::text(
+            Message::Ring { sound: Sound::Beep }.to_json(),
+        ))
+        .await
+        .unwrap();
+    follower
+        .send(tungstenite::Message::text(state.to_json()))
+        .await
+        .unwrap();
+    let nothing =
+        tokio::time::timeout(std::time::Duration::from_millis(100), controller.next()).await;
+    assert!(nothing.is_err());
+
+    controller
+        .send(tungstenite::Message::text(state.to_json()))
+        .await
+        .unwrap();
+
+    let received = follower.next().await.unwrap().unwrap();
+    assert_eq!(received.to_text().unwrap(), state.to_json());
+    let nothing =
+        tokio::time::timeout(std::time::Duration::from_millis(100), stranger.next()).await;
+    assert!(nothing.is_err());
+
+    // a follower joining later gets the current state right away
+    let (mut late, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+    late.send(join("gym", Role::Follower)).await.unwrap();
+    let received = late.next().await.unwrap().unwrap();
+    assert_eq!(received.to_text().unwrap(), state.to_json());
+
+    drop(follower);
+    drop(late);
+    while rooms.len("gym") > 1 {
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+}
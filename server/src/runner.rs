@@ -0,0 +1,202 @@
+use serde::Serialize;
+use sport::defaults::{DEFAULT_INTERVAL, find};
+use sport::errors::ErrorKind;
+use sport::player::Player;
+use sport::sequence::Sequence;
+use sport::signal::SoundSignal;
+use sport::sound::Sound;
+use sport::template::Values;
+use sport::timer::{Phase, Timer};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// No speakers on a server, sounds are only logged
+#[derive(Clone, Debug, Default)]
+pub struct LogPlayer;
+
+impl Player for LogPlayer {
+    fn play(&self, sound: &Sound) -> Result<(), ErrorKind> {
+        tracing::info!("ring {sound}");
+        Ok(())
+    }
+    fn muted(&self, _sound: &Sound) -> Option<Result<bool, ErrorKind>> {
+        Some(Ok(false))
+    }
+    fn unmute(&self, _sound: &Sound) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Command {
+    Load(Box<Sequence>),
+    Start,
+    Pause,
+    Toggle,
+    Next,
+    Previous,
+    RestartWorkout,
+    RestartSequence,
+    Status,
+}
+
+impl Command {
+    /// Parse a text command such as `next` or `load boxing-rounds`
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if let Some(slug) = text.strip_prefix("load ") {
+            return find(slug.trim(), &Values::default()).map(|s| Self::Load(Box::new(s)));
+        }
+        Some(match text {
+            "start" => Self::Start,
+            "pause" => Self::Pause,
+            "toggle" => Self::Toggle,
+            "next" => Self::Next,
+            "previous" => Self::Previous,
+            "restart_workout" => Self::RestartWorkout,
+            "restart_sequence" => Self::RestartSequence,
+            "status" => Self::Status,
+            _ => return None,
+        })
+    }
+}
+
+/// What pollers need to display the timer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TimerStatus {
+    pub sequence: String,
+    pub slug: String,
+    pub label: String,
+    /// Index of the current workout, `None` while preparing
+    pub index: Option<usize>,
+    pub workouts: usize,
+    /// Seconds left in the current workout or in the preparation
+    pub left: u64,
+    pub running: bool,
+    pub phase: Phase,
+}
+
+impl From<&Timer> for TimerStatus {
+    fn from(timer: &Timer) -> Self {
+        Self {
+            sequence: timer.sequence().name().to_string(),
+            slug: timer.sequence().slug(),
+            label: timer.label().to_string(),
+            index: timer.sequence().index(),
+            workouts: timer.sequence().len(),
+            left: timer.left().as_secs(),
+            running: timer.status().running(),
+            phase: timer.phase(),
+        }
+    }
+}
+
+type Request = (Command, oneshot::Sender<TimerStatus>);
+
+/// A `Timer` is not `Send`, it lives on a dedicated thread driven through commands
+#[derive(Clone, Debug)]
+pub struct Runner {
+    requests: mpsc::Sender<Request>,
+}
+
+fn apply(timer: &mut Timer, command: Command) {
+    match command {
+        Command::Load(sequence) => {
+            *timer = Timer::from_sequence(&sequence, timer.sound_signal(), Box::new(LogPlayer));
+        }
+        Command::Start => {
+            if !timer.status().running() {
+                timer.toggle();
+            }
+        }
+        Command::Pause => {
+            if timer.status().running() {
+                timer.toggle();
+            }
+        }
+        Command::Toggle => timer.toggle(),
+        Command::Next => timer.manual_next(),
+        Command::Previous => timer.manual_previous(),
+        Command::RestartWorkout => timer.restart_workout(),
+        Command::RestartSequence => timer.restart_sequence(),
+        Command::Status => {}
+    }
+}
+
+impl Runner {
+    /// Start the timer thread, `on_tick` sees the timer after every tick
+    pub fn spawn(mut on_tick: impl FnMut(&Timer) + Send + 'static) -> Self {
+        let (requests, receiver) = mpsc::channel::<Request>();
+        std::thread::spawn(move || {
+            let interval = Duration::from_millis(u64::from(DEFAULT_INTERVAL));
+            let mut timer = Timer::from_sequence(
+                &Sequence::default(),
+                &SoundSignal::default(),
+                Box::new(LogPlayer),
+            );
+            let mut next_tick = Instant::now() + interval;
+            loop {
+                match receiver.recv_timeout(next_tick.saturating_duration_since(Instant::now())) {
+                    Ok((command, reply)) => {
+                        apply(&mut timer, command);
+                        let _ = reply.send(TimerStatus::from(&timer));
+                    }
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        next_tick += interval;
+                        // like the web timer, a workout change leaves its first second displayed
+                        if timer.tick() {
+                            next_tick += interval;
+                        }
+                        on_tick(&timer);
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        });
+        Self { requests }
+    }
+    pub async fn send(&self, command: Command) -> Result<TimerStatus, ErrorKind> {
+        let (reply, status) = oneshot::channel();
+        self.requests
+            .send((command, reply))
+            .map_err(|err| ErrorKind::RuntimeError(format!("timer thread stopped: {err}")))?;
+        status
+            .await
+            .map_err(|err| ErrorKind::RuntimeError(format!("timer thread stopped: {err}")))
+    }
+}
+
+#[tokio::test]
+async fn runner_tests() {
+    use sport::defaults::SEQUENCES;
+
+    let runner = Runner::spawn(|_| {});
+    let status = runner.send(Command::Status).await.unwrap();
+    assert_eq!(status.workouts, 0);
+
+    let sequence = SEQUENCES.first().unwrap().clone();
+    let status = runner
+        .send(Command::Load(Box::new(sequence.clone())))
+        .await
+        .unwrap();
+    assert_eq!(status.slug, sequence.slug());
+    assert_eq!(status.index, None);
+    assert!(!status.running);
+
+    assert!(runner.send(Command::Start).await.unwrap().running);
+    assert!(runner.send(Command::Start).await.unwrap().running);
+    let status = runner.send(Command::Next).await.unwrap();
+    assert_eq!(status.index, Some(0));
+    assert_eq!(status.label, sequence.first().unwrap().item().name());
+    assert!(!runner.send(Command::Pause).await.unwrap().running);
+    assert!(runner.send(Command::Toggle).await.unwrap().running);
+
+    assert!(matches!(Command::parse(" next\n"), Some(Command::Next)));
+    assert!(matches!(
+        Command::parse(&format!("load {}", sequence.slug())),
+        Some(Command::Load(_))
+    ));
+    assert!(Command::parse("load unknown").is_none());
+    assert!(Command::parse("jump").is_none());
+}
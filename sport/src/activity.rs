@@ -0,0 +1,81 @@
+use crate::tag::{Mouvement, Tag, Tags};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Sport type understood by watch ecosystems
+#[derive(Display, Default, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Activity {
+    Boxing,
+    Running,
+    Strength,
+    Cardio,
+    Flexibility,
+    Rest,
+    #[default]
+    Training,
+}
+
+impl Activity {
+    /// The most specific tag wins, boxing before anything else
+    pub fn from_tags(tags: &Tags) -> Self {
+        if tags.contains(&Tag::Rest) || tags.contains(&Tag::Drink) {
+            return Self::Rest;
+        }
+        if tags.iter().any(|tag| matches!(tag, Tag::Boxing(_))) {
+            return Self::Boxing;
+        }
+        if tags.contains(&Tag::HiiT) {
+            return Self::Cardio;
+        }
+        tags.iter()
+            .find_map(|tag| match tag {
+                Tag::Mouvement(Mouvement::Footwork) => Some(Self::Running),
+                Tag::Mouvement(Mouvement::Strength) | Tag::Plank(_) | Tag::Squat(_) => {
+                    Some(Self::Strength)
+                }
+                Tag::Mouvement(Mouvement::Stamina | Mouvement::Dynamic) => Some(Self::Cardio),
+                Tag::Mouvement(Mouvement::Stretching) | Tag::WarmUp => Some(Self::Flexibility),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+    /// TCX only knows running, biking and other
+    pub fn tcx(self) -> &'static str {
+        match self {
+            Self::Running => "Running",
+            _ => "Other",
+        }
+    }
+    /// FIT `sport` and `sub_sport` profile values
+    pub fn fit(self) -> (u8, u8) {
+        match self {
+            Self::Boxing => (47, 0),
+            Self::Running => (1, 0),
+            Self::Strength => (10, 20),
+            Self::Cardio => (10, 26),
+            Self::Flexibility => (10, 19),
+            Self::Rest | Self::Training => (10, 0),
+        }
+    }
+}
+
+#[test]
+fn activity_tests() {
+    use crate::item::Item;
+    use crate::item_list::ItemList;
+    use crate::tag::Boxing;
+
+    assert_eq!(Activity::from_tags(&vec![Tag::Rest].into()), Activity::Rest);
+    assert_eq!(
+        Activity::from_tags(
+            &vec![Tag::Mouvement(Mouvement::Strength), Boxing::Punch.into()].into()
+        ),
+        Activity::Boxing
+    );
+    assert_eq!(Activity::from_tags(&Tags::default()), Activity::Training);
+    let run: Item = ItemList::Run.into();
+    assert_eq!(run.activity(), Activity::Running);
+    assert_eq!(Activity::Running.tcx(), "Running");
+    assert_eq!(Activity::Boxing.fit(), (47, 0));
+}
@@ -0,0 +1,464 @@
+use crate::duration::DurationExt;
+use crate::i18n::{Locale, Text};
+use crate::sequence::Sequence;
+use crate::tag::Difficulty;
+use pdf_writer::{Content, Name, Pdf, Rect, Ref, Str};
+use std::time::Duration;
+
+/// One interval of a printable card
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+    /// Rounds count from one and end with a rest
+    pub round: usize,
+    pub name: String,
+    pub duration: Duration,
+    pub rest: bool,
+    pub description: Option<String>,
+}
+
+/// A sequence laid out to be printed and taped to the wall
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Card {
+    pub icon: Option<char>,
+    pub name: String,
+    pub description: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    pub total: Duration,
+    pub work: Duration,
+    pub rest: Duration,
+    pub rounds: usize,
+    pub rows: Vec<Row>,
+    /// Language of the labels, item names stay as they are
+    pub locale: Locale,
+}
+
+impl From<&Sequence> for Card {
+    fn from(sequence: &Sequence) -> Self {
+        let mut round = 1;
+        let mut rows = Vec::new();
+        for workout in sequence.iter() {
+            rows.push(Row {
+                round,
+                name: workout.item().name().to_string(),
+                duration: *workout.duration(),
+                rest: workout.is_rest(),
+                description: workout.description().clone(),
+            });
+            if workout.is_rest() {
+                round += 1;
+            }
+        }
+        let rounds = rows
+            .iter()
+            .filter(|row| !row.rest)
+            .map(|row| row.round)
+            .max()
+            .unwrap_or_default();
+        Self {
+            icon: sequence.icon(),
+            name: sequence.name().to_string(),
+            description: sequence.description().clone(),
+            difficulty: sequence.difficulty(),
+            total: sequence.total(),
+            work: sequence.workout_total(),
+            rest: sequence.rest_total(),
+            rounds,
+            rows,
+            locale: Locale::default(),
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// PDF base fonts only know WinAnsi, other characters are dropped
+fn win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .filter_map(|character| match character {
+            ' '..='~' => u8::try_from(character).ok(),
+            '\u{a0}'..='\u{ff}' => u8::try_from(u32::from(character)).ok(),
+            '‘' | '’' => Some(b'\''),
+            '“' | '”' => Some(b'"'),
+            '–' | '—' => Some(b'-'),
+            '…' => Some(0x85),
+            _ => None,
+        })
+        .collect()
+}
+
+fn shorten(text: &str, length: usize) -> String {
+    if text.chars().count() <= length {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(length.saturating_sub(1)).collect();
+    short.push('…');
+    short
+}
+
+impl Card {
+    #[must_use]
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+    fn text(&self, text: Text) -> &'static str {
+        self.locale.text(text)
+    }
+    /// Column titles of the table
+    fn header(&self) -> [&'static str; 5] {
+        [
+            self.text(Text::Round),
+            "#",
+            self.text(Text::Exercise),
+            self.text(Text::Duration),
+            self.text(Text::Notes),
+        ]
+    }
+    pub fn title(&self) -> String {
+        match self.icon {
+            Some(icon) => format!("{icon} {}", self.name),
+            None => self.name.clone(),
+        }
+    }
+    /// Difficulty and totals on one line
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(difficulty) = self.difficulty {
+            parts.push(format!("{}: {difficulty}", self.text(Text::Difficulty)));
+        }
+        parts.push(format!(
+            "{}: {}",
+            self.text(Text::Total),
+            self.total.to_string()
+        ));
+        parts.push(format!(
+            "{}: {}",
+            self.text(Text::Work),
+            self.work.to_string()
+        ));
+        parts.push(format!(
+            "{}: {}",
+            self.text(Text::Rest),
+            self.rest.to_string()
+        ));
+        parts.push(format!("{}: {}", self.text(Text::Rounds), self.rounds));
+        parts.join(" · ")
+    }
+    /// Rows with the round shown on the first row of each round only
+    fn grouped(&self) -> impl Iterator<Item = (Option<usize>, usize, &Row)> {
+        self.rows.iter().enumerate().map(|(index, row)| {
+            let first = index == 0 || self.rows[index - 1].round != row.round;
+            (first.then_some(row.round), index + 1, row)
+        })
+    }
+    pub fn markdown(&self) -> String {
+        let mut markdown = format!("# {}\n\n", escape_markdown(&self.title()));
+        if let Some(description) = &self.description {
+            markdown.push_str(&format!("_{}_\n\n", escape_markdown(description)));
+        }
+        markdown.push_str(&format!("{}\n\n", self.summary()));
+        markdown.push_str(&format!("| {} |\n", self.header().join(" | ")));
+        markdown.push_str("|---:|---:|---|---:|---|\n");
+        for (round, index, row) in self.grouped() {
+            let name = if row.rest {
+                format!("_{}_", escape_markdown(&row.name))
+            } else {
+                format!("**{}**", escape_markdown(&row.name))
+            };
+            markdown.push_str(&format!(
+                "| {} | {index} | {name} | {} | {} |\n",
+                round.map(|round| round.to_string()).unwrap_or_default(),
+                row.duration.to_string(),
+                escape_markdown(row.description.as_deref().unwrap_or_default()),
+            ));
+        }
+        markdown
+    }
+    /// Standalone page with print styles
+    pub fn html(&self) -> String {
+        let mut html = format!(
+            concat!(
+                "<!DOCTYPE html>\n<html lang=\"{lang}\">\n<head>\n<meta charset=\"utf-8\">\n",
+                "<title>{name}</title>\n<style>\n",
+                "body {{ font-family: sans-serif; margin: 2rem; }}\n",
+                "h1 {{ margin-bottom: 0.25rem; }}\n",
+                "table {{ width: 100%; border-collapse: collapse; font-size: 1.1rem; }}\n",
+                "th, td {{ padding: 0.4rem 0.6rem; text-align: left; vertical-align: top; }}\n",
+                "tbody {{ border-top: 2px solid #000; }}\n",
+                "tr.rest {{ color: #555; background: #eee; font-style: italic; }}\n",
+                ".round, .duration {{ text-align: right; white-space: nowrap; }}\n",
+                "@media print {{ body {{ margin: 0; }} tbody {{ break-inside: avoid; }} }}\n",
+                "</style>\n</head>\n<body>\n<article class=\"card\">\n<h1>{title}</h1>\n",
+            ),
+            lang = self.locale.code(),
+            name = escape_html(&self.name),
+            title = escape_html(&self.title()),
+        );
+        if let Some(description) = &self.description {
+            html.push_str(&format!(
+                "<p class=\"description\">{}</p>\n",
+                escape_html(description)
+            ));
+        }
+        let [round, index, exercise, duration, notes] = self.header();
+        html.push_str(&format!(
+            "<p class=\"summary\">{}</p>\n<table>\n<thead><tr><th class=\"round\">{round}</th><th>{index}</th><th>{exercise}</th><th class=\"duration\">{duration}</th><th>{notes}</th></tr></thead>\n",
+            escape_html(&self.summary())
+        ));
+        for (round, index, row) in self.grouped() {
+            if let Some(round) = round {
+                if index > 1 {
+                    html.push_str("</tbody>\n");
+                }
+                let span = self
+                    .rows
+                    .iter()
+                    .filter(|other| other.round == round)
+                    .count();
+                html.push_str(&format!(
+                    "<tbody>\n<tr{}><td class=\"round\" rowspan=\"{span}\">{round}</td>",
+                    if row.rest { " class=\"rest\"" } else { "" }
+                ));
+            } else {
+                html.push_str(&format!(
+                    "<tr{}>",
+                    if row.rest { " class=\"rest\"" } else { "" }
+                ));
+            }
+            html.push_str(&format!(
+                "<td>{index}</td><td>{}</td><td class=\"duration\">{}</td><td>{}</td></tr>\n",
+                escape_html(&row.name),
+                row.duration.to_string(),
+                escape_html(row.description.as_deref().unwrap_or_default()),
+            ));
+        }
+        if !self.rows.is_empty() {
+            html.push_str("</tbody>\n");
+        }
+        html.push_str("</table>\n</article>\n</body>\n</html>\n");
+        html
+    }
+    /// A4 pages with the base Helvetica fonts, icons are left out
+    pub fn pdf(&self) -> Vec<u8> {
+        const WIDTH: f32 = 595.0;
+        const HEIGHT: f32 = 842.0;
+        const MARGIN: f32 = 40.0;
+        const LINE: f32 = 18.0;
+        const COLUMNS: [f32; 5] = [40.0, 90.0, 120.0, 320.0, 380.0];
+        let regular = Name(b"F1");
+        let bold = Name(b"F2");
+
+        let text = |content: &mut Content, font, size, x, y, text: &str| {
+            content
+                .begin_text()
+                .set_font(font, size)
+                .next_line(x, y)
+                .show(Str(&win_ansi(text)))
+                .end_text();
+        };
+        let titles = self.header();
+        let header = |content: &mut Content, y| {
+            for (x, title) in COLUMNS.into_iter().zip(titles) {
+                text(content, bold, 10.0, x, y, title);
+            }
+        };
+
+        let mut pages = Vec::new();
+        let mut content = Content::new();
+        let mut y = HEIGHT - MARGIN - 20.0;
+        text(&mut content, bold, 20.0, MARGIN, y, &self.name);
+        y -= LINE;
+        if let Some(description) = &self.description {
+            text(
+                &mut content,
+                regular,
+                10.0,
+                MARGIN,
+                y,
+                &shorten(description, 100),
+            );
+            y -= LINE;
+        }
+        text(
+            &mut content,
+            regular,
+            11.0,
+            MARGIN,
+            y,
+            &self.summary().replace('·', "-"),
+        );
+        y -= LINE * 1.5;
+        header(&mut content, y);
+        for (round, index, row) in self.grouped() {
+            y -= LINE;
+            if y < MARGIN {
+                pages.push(content);
+                content = Content::new();
+                y = HEIGHT - MARGIN - 10.0;
+                header(&mut content, y);
+                y -= LINE;
+            }
+            if round.is_some() {
+                content
+                    .set_line_width(1.0)
+                    .move_to(MARGIN, y + LINE - 4.0)
+                    .line_to(WIDTH - MARGIN, y + LINE - 4.0)
+                    .stroke();
+            }
+            if row.rest {
+                content
+                    .set_fill_gray(0.9)
+                    .rect(MARGIN, y - 5.0, WIDTH - 2.0 * MARGIN, LINE - 2.0)
+                    .fill_nonzero()
+                    .set_fill_gray(0.0);
+            }
+            let font = if row.rest { regular } else { bold };
+            let round = round.map(|round| round.to_string()).unwrap_or_default();
+            let cells = [
+                round,
+                index.to_string(),
+                shorten(&row.name, 38),
+                row.duration.to_string(),
+                shorten(row.description.as_deref().unwrap_or_default(), 36),
+            ];
+            for ((x, cell), font) in COLUMNS
+                .into_iter()
+                .zip(cells)
+                .zip([regular, regular, font, regular, regular])
+            {
+                text(&mut content, font, 10.0, x, y, &cell);
+            }
+        }
+        pages.push(content);
+
+        let catalog = Ref::new(1);
+        let tree = Ref::new(2);
+        let regular_font = Ref::new(3);
+        let bold_font = Ref::new(4);
+        let mut pdf = Pdf::new();
+        pdf.catalog(catalog).pages(tree);
+        let ids = (0..pages.len())
+            .map(|index| {
+                let index = i32::try_from(index).unwrap_or(i32::MAX / 2 - 5);
+                (Ref::new(5 + 2 * index), Ref::new(6 + 2 * index))
+            })
+            .collect::<Vec<_>>();
+        pdf.pages(tree)
+            .kids(ids.iter().map(|(page, _)| *page))
+            .count(i32::try_from(ids.len()).unwrap_or(i32::MAX));
+        for ((page, contents), content) in ids.into_iter().zip(pages) {
+            let mut writer = pdf.page(page);
+            writer
+                .parent(tree)
+                .media_box(Rect::new(0.0, 0.0, WIDTH, HEIGHT))
+                .contents(contents);
+            let mut resources = writer.resources();
+            resources
+                .fonts()
+                .pair(regular, regular_font)
+                .pair(bold, bold_font);
+            drop(resources);
+            drop(writer);
+            pdf.stream(contents, &content.finish());
+        }
+        pdf.type1_font(regular_font)
+            .base_font(Name(b"Helvetica"))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+        pdf.type1_font(bold_font)
+            .base_font(Name(b"Helvetica-Bold"))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+        pdf.finish()
+    }
+}
+
+#[test]
+fn card_tests() {
+    use crate::defaults::TEMPLATES;
+    use crate::template::{ParameterKind, Values};
+
+    let template = TEMPLATES
+        .iter()
+        .find(|template| template.slug() == "boxing-rounds")
+        .unwrap();
+    let sequence = template.instantiate(&Values::from([(ParameterKind::Rounds, 3)]));
+    let card = Card::from(&sequence);
+    assert_eq!(card.rounds, 3);
+    assert_eq!(card.rows.len(), sequence.len());
+    assert_eq!(card.total, sequence.total());
+    assert!(card.rows.iter().any(|row| row.rest));
+    assert!(card.summary().contains("Rounds: 3"));
+
+    let markdown = card.markdown();
+    assert!(markdown.starts_with(&format!("# {}", card.title())));
+    assert_eq!(
+        markdown
+            .lines()
+            .filter(|line| line.starts_with("| "))
+            .count(),
+        sequence.len() + 1
+    );
+    assert!(markdown.contains("| 2 |"));
+
+    let html = card.html();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert_eq!(html.matches("<tbody>").count(), 3);
+    assert_eq!(html.matches("<tr").count(), sequence.len() + 1);
+    assert_eq!(escape_html("<b>&"), "&lt;b&gt;&amp;");
+
+    let french = card.clone().with_locale(Locale::Fr);
+    assert!(
+        french
+            .markdown()
+            .contains("| Round | # | Exercice | Durée | Notes |")
+    );
+    assert!(french.summary().contains("Travail: "));
+    assert!(french.html().contains("<html lang=\"fr\">"));
+
+    let pdf = card.pdf();
+    assert!(pdf.starts_with(b"%PDF-"));
+    assert!(pdf.windows(5).any(|window| window == b"%%EOF"));
+    assert!(pdf.windows(13).any(|window| window == b"Boxing rounds"));
+    assert_eq!(win_ansi("Élan 🥊 – x"), b"\xc9lan  - x");
+}
+
+#[test]
+fn card_pagination_tests() {
+    use crate::item_list::ItemList;
+    use crate::sound::Sound;
+
+    // 38 rows under the title, then 41 per page
+    let sequence = Sequence::builder()
+        .name("Long")
+        .sound(Sound::default())
+        .workouts(vec![
+            ItemList::JumpingJack
+                .workout(std::time::Duration::from_secs(30));
+            100
+        ])
+        .build();
+    let pdf = String::from_utf8_lossy(&Card::from(&sequence).pdf()).into_owned();
+    let pages = pdf
+        .match_indices("/Type /Page")
+        .filter(|(index, _)| !pdf[index + 11..].starts_with('s'))
+        .count();
+    assert_eq!(pages, 3);
+    let streams = pdf
+        .split("endstream")
+        .filter(|stream| stream.contains("stream"))
+        .collect::<Vec<_>>();
+    assert_eq!(streams.len(), 3);
+    for stream in streams {
+        assert_eq!(stream.matches("(Exercise)").count(), 1);
+        assert_eq!(stream.matches("(Notes)").count(), 1);
+    }
+    assert_eq!(pdf.matches("(100)").count(), 1);
+}
@@ -0,0 +1,420 @@
+use crate::errors::ErrorKind;
+use crate::instructions::Instructions;
+use crate::item::Item;
+use crate::tag::{Boxing, Difficulty, Mouvement, Tag, Tags};
+use derive_more::{Deref, DerefMut, Display, From, IntoIterator};
+use itertools::Itertools;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use serde::{Deserialize, Serialize};
+use strum_macros::VariantArray;
+
+pub const COMBO_ICON: char = '🥊';
+pub const COMBO_SEPARATOR: &str = "-";
+
+#[derive(Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hand {
+    Lead,
+    Rear,
+}
+
+#[derive(
+    Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, VariantArray,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Technique {
+    Jab,
+    Cross,
+    #[display("Lead Hook")]
+    LeadHook,
+    #[display("Rear Hook")]
+    RearHook,
+    #[display("Lead Uppercut")]
+    LeadUppercut,
+    #[display("Rear Uppercut")]
+    RearUppercut,
+    Slip,
+    Roll,
+    Pull,
+    Pivot,
+    Step,
+}
+
+impl Technique {
+    /// Punch number in the usual 1 to 6 boxing notation, defenses and footwork have none
+    pub fn number(&self) -> Option<u8> {
+        match self {
+            Self::Jab => Some(1),
+            Self::Cross => Some(2),
+            Self::LeadHook => Some(3),
+            Self::RearHook => Some(4),
+            Self::LeadUppercut => Some(5),
+            Self::RearUppercut => Some(6),
+            _ => None,
+        }
+    }
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Self::Jab),
+            2 => Some(Self::Cross),
+            3 => Some(Self::LeadHook),
+            4 => Some(Self::RearHook),
+            5 => Some(Self::LeadUppercut),
+            6 => Some(Self::RearUppercut),
+            _ => None,
+        }
+    }
+    pub fn notation(&self) -> String {
+        if let Some(number) = self.number() {
+            return number.to_string();
+        }
+        match self {
+            Self::Slip => "S",
+            Self::Roll => "R",
+            Self::Pull => "P",
+            Self::Pivot => "PIV",
+            _ => "STEP",
+        }
+        .to_string()
+    }
+    pub fn hand(&self) -> Option<Hand> {
+        match self {
+            Self::Jab | Self::LeadHook | Self::LeadUppercut => Some(Hand::Lead),
+            Self::Cross | Self::RearHook | Self::RearUppercut => Some(Hand::Rear),
+            _ => None,
+        }
+    }
+    pub fn is_punch(&self) -> bool {
+        self.number().is_some()
+    }
+    pub fn is_hook(&self) -> bool {
+        matches!(self, Self::LeadHook | Self::RearHook)
+    }
+    pub fn is_uppercut(&self) -> bool {
+        matches!(self, Self::LeadUppercut | Self::RearUppercut)
+    }
+    pub fn is_defense(&self) -> bool {
+        matches!(self, Self::Slip | Self::Roll | Self::Pull)
+    }
+    pub fn is_footwork(&self) -> bool {
+        matches!(self, Self::Pivot | Self::Step)
+    }
+    /// Coaching cue shown in the combination instructions
+    pub fn cue(&self) -> &'static str {
+        match self {
+            Self::Jab => "Jab: snap the lead hand straight out and back to the chin",
+            Self::Cross => "Cross: turn the rear hip and extend the rear hand",
+            Self::LeadHook => "Lead Hook: pivot on the lead foot, elbow at shoulder height",
+            Self::RearHook => "Rear Hook: turn the rear hip, elbow at shoulder height",
+            Self::LeadUppercut => "Lead Uppercut: dip the lead knee and drive the fist up",
+            Self::RearUppercut => "Rear Uppercut: dip the rear knee and drive the fist up",
+            Self::Slip => "Slip: bend the knees and move the head off the centre line",
+            Self::Roll => "Roll: bend the knees and roll under the punch",
+            Self::Pull => "Pull: lean back out of range, weight on the rear foot",
+            Self::Pivot => "Pivot: turn on the ball of the lead foot",
+            Self::Step => "Step: move the lead foot first and keep the stance",
+        }
+    }
+    pub fn tag(&self) -> Tag {
+        match self {
+            Self::Slip => Boxing::Slip.into(),
+            Self::Roll => Boxing::Roll.into(),
+            Self::Pull => Boxing::Pull.into(),
+            Self::Pivot | Self::Step => Mouvement::Footwork.into(),
+            _ => Boxing::Punch.into(),
+        }
+    }
+}
+
+impl std::str::FromStr for Technique {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let token = s.trim();
+        if let Ok(number) = token.parse::<u8>() {
+            return Self::from_number(number)
+                .ok_or_else(|| ErrorKind::InvalidCombo(format!("unknown punch number {number}")));
+        }
+        match token.to_lowercase().as_str() {
+            "jab" => Ok(Self::Jab),
+            "cross" => Ok(Self::Cross),
+            "s" | "slip" => Ok(Self::Slip),
+            "r" | "roll" => Ok(Self::Roll),
+            "p" | "pull" => Ok(Self::Pull),
+            "piv" | "pivot" => Ok(Self::Pivot),
+            "step" => Ok(Self::Step),
+            _ => Err(ErrorKind::InvalidCombo(format!(
+                "unknown technique {token:?}"
+            ))),
+        }
+    }
+}
+
+#[derive(
+    Default,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Deref,
+    DerefMut,
+    IntoIterator,
+    From,
+    Serialize,
+    Deserialize,
+)]
+#[serde(transparent)]
+pub struct Combo(Vec<Technique>);
+
+impl Combo {
+    /// Techniques joined by name, e.g. "Jab | Cross | Lead Hook"
+    pub fn names(&self) -> String {
+        self.0.iter().map(Technique::to_string).join(" | ")
+    }
+    pub fn punches(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|technique| technique.is_punch())
+            .count()
+    }
+    pub fn tags(&self) -> Tags {
+        self.0
+            .iter()
+            .map(Technique::tag)
+            .unique()
+            .collect_vec()
+            .into()
+    }
+    pub fn item(&self) -> Item {
+        Item::builder()
+            .name(format!("{} ({self})", self.names()))
+            .tags(self.tags())
+            .icon(COMBO_ICON)
+            .instructions(
+                Instructions::builder()
+                    .setup("Guard up, chin down, lead foot forward")
+                    .steps(&self.0.iter().map(Technique::cue).collect_vec())
+                    .mistakes(&["Dropping the hands", "Loading up before punching"])
+                    .breathing("Exhale sharply on every punch")
+                    .build(),
+            )
+            .build()
+    }
+}
+
+impl Combo {
+    /// Number of techniques a random combination has for a difficulty
+    pub fn lengths(difficulty: Difficulty) -> std::ops::RangeInclusive<usize> {
+        match difficulty {
+            Difficulty::Easy => 2..=3,
+            Difficulty::Medium => 3..=4,
+            Difficulty::Hard => 4..=5,
+            Difficulty::Elite => 5..=6,
+        }
+    }
+    /// Techniques a random combination may use for a difficulty
+    pub fn techniques(difficulty: Difficulty) -> Vec<Technique> {
+        let mut techniques = vec![Technique::Jab, Technique::Cross, Technique::LeadHook];
+        if difficulty == Difficulty::Easy {
+            return techniques;
+        }
+        techniques.extend([
+            Technique::RearHook,
+            Technique::LeadUppercut,
+            Technique::RearUppercut,
+            Technique::Slip,
+            Technique::Roll,
+        ]);
+        if difficulty == Difficulty::Medium {
+            return techniques;
+        }
+        techniques.push(Technique::Pull);
+        if difficulty == Difficulty::Hard {
+            return techniques;
+        }
+        techniques.extend([Technique::Pivot, Technique::Step]);
+        techniques
+    }
+    /// Generate a random but sensible combination:
+    /// - it opens with a punch, easy combinations always open with the jab
+    /// - punches alternate hands, except the double jab
+    /// - a hook is followed by a defense, or only closes the combination when no defense is allowed
+    /// - a defense is never followed by another defense
+    /// - footwork only closes a combination to exit on an angle
+    pub fn random<R: Rng + ?Sized>(difficulty: Difficulty, rng: &mut R) -> Self {
+        let allowed = Self::techniques(difficulty);
+        let defended = allowed.iter().any(Technique::is_defense);
+        let length = rng.random_range(Self::lengths(difficulty));
+        let mut combo: Vec<Technique> = Vec::with_capacity(length);
+        while combo.len() < length {
+            let last = combo.last().copied();
+            let closing = combo.len() + 1 == length;
+            let candidates = allowed
+                .iter()
+                .copied()
+                .filter(|technique| match last {
+                    None => {
+                        if difficulty == Difficulty::Easy {
+                            *technique == Technique::Jab
+                        } else {
+                            technique.is_punch()
+                        }
+                    }
+                    Some(last) if last.is_hook() => technique.is_defense(),
+                    Some(last) if last.is_defense() => technique.is_punch(),
+                    Some(last) if last.is_footwork() => false,
+                    Some(last) => {
+                        if technique.is_footwork() {
+                            closing
+                        } else if technique.is_defense() {
+                            false
+                        } else {
+                            (technique.hand() != last.hand()
+                                || (last == Technique::Jab && *technique == Technique::Jab))
+                                && (defended || closing || !technique.is_hook())
+                        }
+                    }
+                })
+                .collect_vec();
+            let Some(technique) = candidates.choose(rng) else {
+                break;
+            };
+            combo.push(*technique);
+        }
+        Self(combo)
+    }
+    pub fn randoms<R: Rng + ?Sized>(
+        difficulty: Difficulty,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Self> {
+        (0..count)
+            .map(|_| Self::random(difficulty, rng))
+            .collect_vec()
+    }
+}
+
+impl std::fmt::Display for Combo {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0.iter().map(Technique::notation).join(COMBO_SEPARATOR)
+        )
+    }
+}
+
+impl std::str::FromStr for Combo {
+    type Err = ErrorKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ErrorKind::InvalidCombo("empty combination".to_string()));
+        }
+        let techniques = s
+            .split(COMBO_SEPARATOR)
+            .map(str::parse)
+            .collect::<Result<Vec<Technique>, _>>()?;
+        Ok(Self(techniques))
+    }
+}
+
+impl From<Combo> for Item {
+    fn from(combo: Combo) -> Self {
+        combo.item()
+    }
+}
+
+#[test]
+fn combo_parse_tests() {
+    let combo: Combo = "1-2-3-ROLL-3-2".parse().unwrap();
+    assert_eq!(
+        combo.as_slice(),
+        &[
+            Technique::Jab,
+            Technique::Cross,
+            Technique::LeadHook,
+            Technique::Roll,
+            Technique::LeadHook,
+            Technique::Cross
+        ]
+    );
+    assert_eq!(combo.to_string(), "1-2-3-R-3-2");
+    assert_eq!(
+        combo.names(),
+        "Jab | Cross | Lead Hook | Roll | Lead Hook | Cross"
+    );
+    assert_eq!(combo.punches(), 5);
+    assert_eq!(
+        combo.tags(),
+        Tags::from(vec![Boxing::Punch.into(), Boxing::Roll.into()])
+    );
+    assert_eq!(
+        combo.item().name(),
+        "Jab | Cross | Lead Hook | Roll | Lead Hook | Cross (1-2-3-R-3-2)"
+    );
+    let instructions = combo.item().instructions().cloned().unwrap();
+    assert_eq!(instructions.steps().len(), combo.len());
+    assert!(instructions.steps()[3].starts_with("Roll"));
+
+    let combo: Combo = "1-2-s-6-piv".parse().unwrap();
+    assert_eq!(combo.to_string(), "1-2-S-6-PIV");
+    assert!(combo.tags().contains(&Mouvement::Footwork.into()));
+
+    assert!("".parse::<Combo>().is_err());
+    assert!("1-7".parse::<Combo>().is_err());
+    assert!("1--2".parse::<Combo>().is_err());
+}
+
+#[test]
+fn combo_random_tests() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use strum::VariantArray;
+
+    let first = Combo::randoms(Difficulty::Hard, 20, &mut StdRng::seed_from_u64(42));
+    let second = Combo::randoms(Difficulty::Hard, 20, &mut StdRng::seed_from_u64(42));
+    assert_eq!(first, second);
+
+    let mut rng = StdRng::seed_from_u64(7);
+    for difficulty in [
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Elite,
+    ] {
+        let allowed = Combo::techniques(difficulty);
+        for combo in Combo::randoms(difficulty, 200, &mut rng) {
+            assert!(combo.first().is_some_and(Technique::is_punch), "{combo}");
+            assert!(combo.len() <= *Combo::lengths(difficulty).end(), "{combo}");
+            assert!(combo.iter().all(|t| allowed.contains(t)), "{combo}");
+            for (previous, next) in combo.iter().tuple_windows() {
+                if previous.is_hook() {
+                    assert!(next.is_defense(), "{combo}");
+                }
+                assert!(!(previous.is_defense() && next.is_defense()), "{combo}");
+                if previous.is_punch() && next.is_punch() && previous.hand() == next.hand() {
+                    assert_eq!(
+                        (*previous, *next),
+                        (Technique::Jab, Technique::Jab),
+                        "{combo}"
+                    );
+                }
+            }
+            if difficulty == Difficulty::Easy {
+                assert_eq!(combo.first(), Some(&Technique::Jab), "{combo}");
+            }
+            assert!(
+                combo.len() >= *Combo::lengths(difficulty).start(),
+                "{combo}"
+            );
+        }
+    }
+    assert!(
+        Technique::VARIANTS
+            .iter()
+            .all(|t| Combo::techniques(Difficulty::Elite).contains(t))
+    );
+}
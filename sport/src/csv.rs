@@ -0,0 +1,354 @@
+use crate::duration::DurationExt;
+use crate::errors::ErrorKind;
+use crate::item::Item;
+use crate::item_list::ItemList;
+use crate::sequence::Sequence;
+use crate::sound::Sound;
+use crate::tag::{Difficulty, Tag, Tags};
+use itertools::Itertools;
+use slug::slugify;
+use std::time::Duration;
+
+pub const SEQUENCE_HEADER: [&str; 5] = ["item", "duration", "difficulty", "tags", "rest"];
+pub const ITEM_HEADER: [&str; 5] = ["item", "icon", "difficulty", "tags", "description"];
+
+fn invalid(row: usize, reason: impl std::fmt::Display) -> ErrorKind {
+    ErrorKind::InvalidCsv(row, reason.to_string())
+}
+
+fn field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn line<S: AsRef<str>>(fields: impl IntoIterator<Item = S>) -> String {
+    let mut line = fields
+        .into_iter()
+        .map(|text| field(text.as_ref()))
+        .join(",");
+    line.push('\n');
+    line
+}
+
+/// Semicolon when the first row has more semicolons than commas, outside quotes
+fn delimiter(text: &str) -> char {
+    let mut quoted = false;
+    let (mut commas, mut semicolons) = (0, 0);
+    for character in text.chars() {
+        match (quoted, character) {
+            (_, '"') => quoted = !quoted,
+            (false, '\n') => break,
+            (false, ',') => commas += 1,
+            (false, ';') => semicolons += 1,
+            _ => {}
+        }
+    }
+    if semicolons > commas { ';' } else { ',' }
+}
+
+/// Rows of fields, quoted fields may hold delimiters, quotes and line breaks
+fn rows(text: &str) -> Result<Vec<Vec<String>>, ErrorKind> {
+    let delimiter = delimiter(text);
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        match (quoted, character) {
+            (true, '"') if characters.peek() == Some(&'"') => {
+                characters.next();
+                current.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, _) => current.push(character),
+            (false, '"') if current.is_empty() => quoted = true,
+            (false, _) if character == delimiter => row.push(std::mem::take(&mut current)),
+            (false, '\r') => {}
+            (false, '\n') => {
+                row.push(std::mem::take(&mut current));
+                rows.push(std::mem::take(&mut row));
+            }
+            (false, _) => current.push(character),
+        }
+    }
+    if quoted {
+        return Err(invalid(rows.len() + 1, "unterminated quote"));
+    }
+    if !current.is_empty() || !row.is_empty() {
+        row.push(current);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Numbered data rows, skipping the header and blank lines
+fn records(text: &str, header: &str) -> Result<Vec<(usize, Vec<String>)>, ErrorKind> {
+    Ok(rows(text)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| (index + 1, row))
+        .filter(|(number, row)| {
+            let first = row.first().map(|cell| cell.trim()).unwrap_or_default();
+            !(row.iter().all(|cell| cell.trim().is_empty())
+                || (*number == 1 && first.eq_ignore_ascii_case(header)))
+        })
+        .collect())
+}
+
+fn cell(row: &[String], index: usize) -> &str {
+    row.get(index).map_or("", |cell| cell.trim())
+}
+
+/// Serde name of a tag, like `punch` or `warm_up`
+fn tag_name(tag: &Tag) -> String {
+    serde_json::to_value(tag)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_else(|| tag.slug())
+}
+
+fn tags(tags: &Tags) -> String {
+    tags.iter().map(tag_name).join(" ")
+}
+
+/// Tags separated by spaces or slashes, in any case
+fn parse_tags(row: usize, text: &str) -> Result<Tags, ErrorKind> {
+    text.split([' ', '/', '|'])
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| {
+            let name = tag.to_lowercase().replace('-', "_");
+            serde_json::from_value(serde_json::Value::String(name))
+                .map_err(|_| invalid(row, format!("unknown tag {tag}")))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Tags::from)
+}
+
+fn difficulty(difficulty: Option<Difficulty>) -> String {
+    difficulty
+        .map(|difficulty| difficulty.to_string().to_lowercase())
+        .unwrap_or_default()
+}
+
+fn parse_difficulty(row: usize, text: &str) -> Result<Option<Difficulty>, ErrorKind> {
+    if text.is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_value(serde_json::Value::String(text.to_lowercase()))
+        .map(Some)
+        .map_err(|_| invalid(row, format!("unknown difficulty {text}")))
+}
+
+/// Seconds, `45s` or `m:ss`
+fn parse_duration(row: usize, text: &str) -> Result<Duration, ErrorKind> {
+    let error = || invalid(row, format!("invalid duration {text:?}"));
+    let seconds = match text.split_once(':') {
+        Some((minutes, seconds)) => minutes
+            .parse::<u64>()
+            .ok()
+            .zip(seconds.parse::<u64>().ok().filter(|seconds| *seconds < 60))
+            .and_then(|(minutes, seconds)| minutes.checked_mul(60)?.checked_add(seconds)),
+        None => text.strip_suffix('s').unwrap_or(text).parse().ok(),
+    }
+    .ok_or_else(error)?;
+    if seconds == 0 {
+        return Err(error());
+    }
+    Ok(Duration::from_secs(seconds))
+}
+
+fn parse_rest(row: usize, text: &str) -> Result<bool, ErrorKind> {
+    match text.to_lowercase().as_str() {
+        "" | "false" | "no" | "0" => Ok(false),
+        "true" | "yes" | "1" | "x" => Ok(true),
+        _ => Err(invalid(
+            row,
+            format!("rest should be true or false, not {text}"),
+        )),
+    }
+}
+
+/// One row per workout
+pub fn sequence(sequence: &Sequence) -> String {
+    let mut csv = line(SEQUENCE_HEADER);
+    for workout in sequence.iter() {
+        csv.push_str(&line([
+            workout.item().name().to_string(),
+            workout.duration().to_string(),
+            difficulty(workout.difficulty()),
+            tags(workout.item().tags()),
+            workout.is_rest().to_string(),
+        ]));
+    }
+    csv
+}
+
+/// Items are found by name or slug in the catalog, unknown ones are created from the row
+pub fn parse_sequence(name: &str, csv: &str) -> Result<Sequence, ErrorKind> {
+    let mut workouts = Vec::new();
+    for (row, fields) in records(csv, SEQUENCE_HEADER[0])? {
+        let name = cell(&fields, 0);
+        if name.is_empty() {
+            return Err(invalid(row, "missing item"));
+        }
+        let duration = parse_duration(row, cell(&fields, 1))?;
+        let difficulty = parse_difficulty(row, cell(&fields, 2))?;
+        let rest = parse_rest(row, cell(&fields, 4))?;
+        let item = match ItemList::find(&slugify(name)) {
+            Some(item) if item.is_rest() != rest => {
+                return Err(invalid(
+                    row,
+                    format!("{name} is {}a rest", if rest { "not " } else { "" }),
+                ));
+            }
+            Some(item) => item,
+            None => {
+                let mut tags = parse_tags(row, cell(&fields, 3))?;
+                if rest && !tags.contains(&Tag::Rest) {
+                    tags.push(Tag::Rest);
+                }
+                if !rest && tags.contains(&Tag::Rest) {
+                    return Err(invalid(row, format!("{name} is tagged rest")));
+                }
+                Item::builder().name(name).tags(tags).build()
+            }
+        };
+        workouts.push(match difficulty {
+            Some(difficulty) => item.difficulty(duration, difficulty),
+            None => item.workout(duration),
+        });
+    }
+    if workouts.is_empty() {
+        return Err(ErrorKind::InvalidSequence);
+    }
+    Ok(Sequence::builder()
+        .name(name)
+        .sound(Sound::default())
+        .workouts(workouts)
+        .build())
+}
+
+/// One row per item of the catalog
+pub fn items(items: &[Item]) -> String {
+    let mut csv = line(ITEM_HEADER);
+    for item in items {
+        csv.push_str(&line([
+            item.name().to_string(),
+            item.icon().map(String::from).unwrap_or_default(),
+            difficulty(item.level()),
+            tags(item.tags()),
+            item.description().clone().unwrap_or_default(),
+        ]));
+    }
+    csv
+}
+
+pub fn parse_items(csv: &str) -> Result<Vec<Item>, ErrorKind> {
+    records(csv, ITEM_HEADER[0])?
+        .into_iter()
+        .map(|(row, fields)| {
+            let name = cell(&fields, 0);
+            if name.is_empty() {
+                return Err(invalid(row, "missing item"));
+            }
+            let mut icon = cell(&fields, 1).chars();
+            let (first, extra) = (icon.next(), icon.next());
+            if extra.is_some() {
+                return Err(invalid(row, "icon should be a single character"));
+            }
+            let description = cell(&fields, 4);
+            Ok(Item::builder()
+                .name(name)
+                .maybe_icon(first)
+                .maybe_difficulty(parse_difficulty(row, cell(&fields, 2))?)
+                .tags(parse_tags(row, cell(&fields, 3))?)
+                .maybe_description((!description.is_empty()).then(|| description.to_string()))
+                .build())
+        })
+        .collect()
+}
+
+#[test]
+fn csv_sequence_tests() {
+    use crate::defaults::SEQUENCES;
+
+    for sequence in SEQUENCES.iter() {
+        let parsed = parse_sequence(sequence.name(), &self::sequence(sequence)).unwrap();
+        assert_eq!(parsed.len(), sequence.len(), "{}", sequence.name());
+        for (parsed, workout) in parsed.iter().zip(sequence.iter()) {
+            assert_eq!(parsed.item().name(), workout.item().name());
+            assert_eq!(parsed.item().tags(), workout.item().tags());
+            assert_eq!(parsed.duration(), workout.duration());
+            assert_eq!(parsed.difficulty(), workout.difficulty());
+        }
+    }
+
+    let csv = "Item;Duration;Difficulty;Tags;Rest\n\
+               jumping-jack;1:30;Hard;;\n\
+               Burpees, slow;45s;;full Stamina;no\n\
+               \n\
+               Walk it off;30;;;yes\n";
+    let sequence = parse_sequence("Spreadsheet", csv).unwrap();
+    assert_eq!(sequence.len(), 3);
+    assert_eq!(sequence[0].item().slug(), "jumping-jack");
+    assert_eq!(*sequence[0].duration(), Duration::from_secs(90));
+    assert_eq!(sequence[0].difficulty(), Some(Difficulty::Hard));
+    assert_eq!(sequence[1].item().name(), "Burpees, slow");
+    assert_eq!(sequence[1].item().tags().len(), 2);
+    assert!(sequence[2].is_rest());
+
+    let error = |csv: &str| parse_sequence("broken", csv).unwrap_err().to_string();
+    assert_eq!(
+        error("item,duration\njumping-jack,30\nrun,1:75\n"),
+        "Invalid CSV at row 3: invalid duration \"1:75\""
+    );
+    assert!(error("jumping-jack,30,impossible\n").contains("row 1: unknown difficulty"));
+    assert!(error("squat,30,,,yes\n").contains("row 1: squat is not a rest"));
+    assert!(error("new one,30,,flying\n").contains("unknown tag flying"));
+    assert!(error(",30\n").contains("missing item"));
+    assert!(error("\"open,30\n").contains("unterminated quote"));
+    assert_eq!(error("item,duration\n"), "Invalid sequence");
+}
+
+#[test]
+fn csv_items_tests() {
+    let catalog = ItemList::items();
+    let csv = items(&catalog);
+    assert_eq!(
+        csv.lines().next(),
+        Some("item,icon,difficulty,tags,description")
+    );
+    let parsed = parse_items(&csv).unwrap();
+    assert_eq!(parsed.len(), catalog.len());
+    for (parsed, item) in parsed.iter().zip(&catalog) {
+        assert_eq!(parsed.name(), item.name());
+        assert_eq!(parsed.icon(), item.icon());
+        assert_eq!(parsed.level(), item.level());
+        assert_eq!(parsed.tags(), item.tags());
+        assert_eq!(parsed.description(), item.description());
+    }
+
+    let item = Item::builder()
+        .name("Slow burpee")
+        .description("Down slowly; up fast")
+        .build();
+    let parsed = parse_items(&items(std::slice::from_ref(&item))).unwrap();
+    assert_eq!(parsed[0].description(), item.description());
+    let parsed =
+        parse_items("item;icon;difficulty;tags;description\nSlow burpee;;;;Down slowly, up fast\n")
+            .unwrap();
+    assert_eq!(
+        parsed[0].description().as_deref(),
+        Some("Down slowly, up fast")
+    );
+    assert_eq!(
+        parse_items("item,icon\nPunch,🥊🥊\n")
+            .unwrap_err()
+            .to_string(),
+        "Invalid CSV at row 2: icon should be a single character"
+    );
+}
@@ -1,11 +1,15 @@
 use crate::exercises::Exercises;
 use crate::item_list::ItemList;
+use crate::program::{Planned, Program};
 use crate::sequence::{ROUNDS, Sequence};
 use crate::sound::Sound;
 use crate::tag::{Body, Difficulty, Tag};
+use crate::template::{Parameter, ParameterKind, Template, Values};
 use itertools::Itertools;
 use natural_sort_rs::Natural;
+use std::collections::HashMap;
 use strum::VariantArray;
+use time::Weekday;
 use time::ext::NumericalStdDuration;
 
 pub const DEFAULT_ICON: char = '🎯';
@@ -20,6 +24,10 @@ pub const PREVIOUS_ITEM: &str = "⏪";
 pub const NEXT_ITEM: &str = "⏩";
 pub const RANDOMIZE: &str = "🎲";
 pub const SIGNAL: &str = "🛎";
+pub const SHADOW_BOXING_SEED: u64 = 2;
+pub const DURATION_CHOICES: &[u64] = &[5, 10, 15, 20, 25, 30, 45, 60];
+/// Body weight in kilograms used for calorie estimations until the user sets theirs
+pub const DEFAULT_WEIGHT: f64 = 70.0;
 
 pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::new(|| {
     let mut sequences = Vec::new();
@@ -105,57 +113,13 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
         .call();
     sequences.push(cardio_warmup);
 
-    for x in [5, 10, 15] {
-        let rounds = Sequence::rounds()
-            .name(&format!("1m/{x}x Workout"))
-            .rounds(x)
-            .workout(ItemList::Workout.workout(1.std_minutes()))
-            .rest(30.std_seconds())
-            .sound(&Sound::Beep)
-            .difficulty(Difficulty::Medium)
-            .icon('🥊')
-            .call();
-        sequences.push(rounds);
-    }
-
-    for x in [2, 3, 4, 6, 12] {
-        let rounds = Sequence::rounds()
-            .name(&format!("{x}x2m"))
-            .rounds(x)
-            .workout(ItemList::BoxingRound.workout(2.std_minutes()))
-            .rest(30.std_seconds())
-            .sound(&Sound::Bell)
-            .icon('🥊')
-            .difficulty(Difficulty::Medium)
-            .call();
-        sequences.push(rounds);
-    }
-
-    for x in [2, 3, 4, 6, 12] {
-        let rounds = Sequence::rounds()
-            .name(&format!("{x}x3m"))
-            .rounds(x)
-            .workout(ItemList::BoxingRound.workout(3.std_minutes()))
-            .rest(1.std_minutes())
-            .sound(&Sound::Bell)
-            .icon('🥊')
-            .difficulty(Difficulty::Hard)
-            .call();
-        sequences.push(rounds);
-    }
-
     let stamina_jab_cross_hook = Sequence::repeat()
         .name("Stamina 30s 1-2-3")
         .description("1 | 2 | 1-2 | 1-2-3")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab (1)",
-                "Cross (2)",
-                "Jab | Cross (1-2)",
-                "Jab | Cross | Hook (1-2-3)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["1", "2", "1-2", "1-2-3"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .icon('🥊')
         .rounds(4 * ROUNDS)
@@ -168,15 +132,10 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let stamina_uppercuts_hook = Sequence::repeat()
         .name("Stamina 30s 5-6-3-2")
         .description("5 | 5-6 | 5-6-3 | 5-6-3-2")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Lead Uppercut (5)",
-                "Lead Uppercut | Rear Uppercut (5-6)",
-                "Lead Uppercut | Rear Uppercut | Hook (5-6-3)",
-                "Lead Uppercut | Rear Uppercut | Hook | Cross (5-6-3-2)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["5", "5-6", "5-6-3", "5-6-3-2"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .icon('🥊')
         .rounds(4 * ROUNDS)
@@ -189,15 +148,10 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let stamina_jab_cross_uppercut_cross = Sequence::repeat()
         .name("Stamina 30s 1-2-5-2")
         .description("1 | 1-2 | 1-2-5 | 1-2-5-2")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab (1)",
-                "Jab | Cross (1-2)",
-                "Jab | Cross | Uppercut (1-2-5)",
-                "Jab | Cross | Uppercut | Cross (1-2-5-2)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["1", "1-2", "1-2-5", "1-2-5-2"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .icon('🥊')
         .rounds(4 * ROUNDS)
@@ -210,15 +164,10 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let stamina_jab_jab_cross_cross = Sequence::repeat()
         .name("Stamina 30s 1-1-2-2")
         .description("1 | 1-1 | 1-1-2 | 1-1-2-2")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab (1)",
-                "Jab | Jab (1-1)",
-                "Jab | Jab | Cross (1-1-2)",
-                "Jab | Jab | Cross | Cross (1-1-2-2)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["1", "1-1", "1-1-2", "1-1-2-2"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .icon('🥊')
         .rounds(4 * ROUNDS)
@@ -231,15 +180,10 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let stamina_jab_cross_hook_cross = Sequence::repeat()
         .name("Stamina 30s 1-2-3-2")
         .description("1 | 1-2 | 1-2-3 | 1-2-3-2")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab (1)",
-                "Jab | Cross (1-2)",
-                "Jab | Cross | Hook (1-2-3)",
-                "Jab | Cross | Hook | Cross (1-2-3-2)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["1", "1-2", "1-2-3", "1-2-3-2"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .rounds(4 * ROUNDS)
         .icon('🥊')
@@ -252,15 +196,10 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let stamina_roll_left = Sequence::repeat()
         .name("Stamina 30s 1-2-3-ROLL-3-2")
         .description("1-2-3 | 1-2-3-ROLL | 1-2-3-ROLL-3 | 1-2-3-ROLL-3-2")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab | Cross | Hook (1-2-3)",
-                "Jab | Cross | Hook | ROLL (1-2-3-R)",
-                "Jab | Cross | Hook | ROLL | Hook (1-2-3-R-3)",
-                "Jab | Cross | Hook | ROLL | Hook | Cross (1-2-3-R-3-2)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["1-2-3", "1-2-3-R", "1-2-3-R-3", "1-2-3-R-3-2"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .icon('🥊')
         .rounds(4 * ROUNDS)
@@ -273,15 +212,10 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let stamina_roll_right = Sequence::repeat()
         .name("Stamina 30s 1-2-ROLL-2-3-2")
         .description("1-2-ROLL | 1-2-ROLL-2 | 1-2-ROLL-2-3 | 1-2-ROLL-2-3-2")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab | Cross | ROLL (1-2-R)",
-                "Jab | Cross | ROLL | Cross (1-2-R-2)",
-                "Jab | Cross | ROLL | Cross | Hook (1-2-R-2-3)",
-                "Jab | Cross | ROLL | Cross | Hook | Cross (1-2-R-2-3-2)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["1-2-R", "1-2-R-2", "1-2-R-2-3", "1-2-R-2-3-2"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .rounds(4 * ROUNDS)
         .rest(1.std_minutes())
@@ -294,19 +228,10 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let _2_boxing_combinations = Sequence::repeat()
         .name("Boxing 2 combos")
         .description("All realistic boxing combinations")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab | Jab (1-1)",
-                "Jab | Cross (1-2)",
-                "Cross | Hook (2-3)",
-                "Lead Uppercut | Rear Uppercut (5-6)",
-                "Lead Uppercut | Cross (5-2)",
-                "Jab | Hook (1-4)",
-                "Rear Uppercut | Hook (6-3)",
-                "Lead Hook | Rear Hook (3-4)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&["1-1", "1-2", "2-3", "5-6", "5-2", "1-4", "6-3", "3-4"])
+                .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .rounds(4 * ROUNDS)
         .rest(1.std_minutes())
@@ -319,22 +244,13 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     let _3_boxing_combinations = Sequence::repeat()
         .name("Boxing 3 combos")
         .description("All realistic boxing combinations")
-        .exercises(Exercises::from_strings(
-            vec![
-                "Jab | Jab | Jab (1-1-1)",
-                "Jab | Jab | Cross (1-1-2)",
-                "Jab | Cross | (Pull) | Cross (1-2-P-2)",
-                "Jab | Cross | (Slip) | Rear Uppercut (1-2-S-6)",
-                "Jab | Cross | Jab (1-2-1)",
-                "Jab | Cross | Hook (1-2-3)",
-                "Cross | Hook | Cross (2-3-2)",
-                "Jab | Cross | Lead Uppercut (1-2-5)",
-                "Lead Uppercut | Cross | Hook (5-2-3)",
-                "Lead Uppercut | Rear Uppercut | Hook (5-6-3)",
-                "Cross | Rear Uppercut | Hook (2-6-3)",
-            ],
-            Some('🥊'),
-        ))
+        .exercises(
+            Exercises::from_combos(&[
+                "1-1-1", "1-1-2", "1-2-P-2", "1-2-S-6", "1-2-1", "1-2-3", "2-3-2", "1-2-5",
+                "5-2-3", "5-6-3", "2-6-3",
+            ])
+            .expect("default combinations are valid"),
+        )
         .workout(30.std_seconds())
         .rounds(4 * ROUNDS)
         .rest(1.std_minutes())
@@ -344,17 +260,24 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
         .call();
     sequences.push(_3_boxing_combinations);
 
-    for x in [4, 8] {
-        let hiit = Sequence::rounds()
-            .name(&format!("HiiT {x}x20s"))
-            .rounds(x)
-            .workout(ItemList::Tabata.workout(20.std_seconds()))
-            .difficulty(Difficulty::Medium)
-            .icon('🧨')
-            .rest(10.std_seconds())
-            .sound(&Sound::Beep)
+    for difficulty in [
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Elite,
+    ] {
+        let shadow_boxing = Sequence::shadow_boxing()
+            .name(&format!("Shadow boxing {difficulty}"))
+            .difficulty(difficulty)
+            .rounds(3 * ROUNDS)
+            .combos(6)
+            .workout(30.std_seconds())
+            .rest(1.std_minutes())
+            .seed(SHADOW_BOXING_SEED)
+            .icon('🥊')
+            .sound(&Sound::Bell)
             .call();
-        sequences.push(hiit);
+        sequences.push(shadow_boxing);
     }
 
     let _4x_hiit_8x = Sequence::rounds()
@@ -368,13 +291,6 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
         .call()
         .cycle(4, 1.std_minutes());
 
-    for x in [1, 2, 3, 5, 10, 15] {
-        let seq = ItemList::Workout
-            .workout(x.std_minutes())
-            .sequence(&Sound::Bell);
-        sequences.push(seq);
-    }
-
     // let mut random_tags = vec![Tag::Boxing];
     let mut random_tags = vec![];
     let mut body_tags = Body::VARIANTS.iter().map(|v| Tag::Body(*v)).collect_vec();
@@ -395,3 +311,151 @@ pub static SEQUENCES: std::sync::LazyLock<Vec<Sequence>> = std::sync::LazyLock::
     sequences.sort_by_key(|s| Natural::str(s.name().to_string()));
     sequences
 });
+
+pub static TEMPLATES: std::sync::LazyLock<Vec<Template>> = std::sync::LazyLock::new(|| {
+    let rounds = |default, max| {
+        Parameter::builder()
+            .kind(ParameterKind::Rounds)
+            .min(1)
+            .max(max)
+            .default(default)
+            .build()
+    };
+    let seconds = |kind, min, max, default, step| {
+        Parameter::builder()
+            .kind(kind)
+            .min(min)
+            .max(max)
+            .default(default)
+            .step(step)
+            .build()
+    };
+    let mut templates = vec![
+        Template::builder()
+            .name("Workout")
+            .description("Generic workout")
+            .item(ItemList::Workout.into())
+            .rounds(Parameter::fixed(ParameterKind::Rounds, 1))
+            .work(seconds(ParameterKind::Work, 60, 3600, 300, 60))
+            .rest(Parameter::fixed(ParameterKind::Rest, 0))
+            .sound(Sound::Bell)
+            .icon('🎯')
+            .build(),
+        Template::builder()
+            .name("Workout rounds")
+            .item(ItemList::Workout.into())
+            .rounds(rounds(5, 20))
+            .work(seconds(ParameterKind::Work, 30, 300, 60, 30))
+            .rest(seconds(ParameterKind::Rest, 10, 120, 30, 10))
+            .sound(Sound::Beep)
+            .difficulty(Difficulty::Medium)
+            .icon('🥊')
+            .build(),
+        Template::builder()
+            .name("Boxing rounds")
+            .item(ItemList::BoxingRound.into())
+            .rounds(rounds(3, 12))
+            .work(seconds(ParameterKind::Work, 60, 300, 180, 30))
+            .rest(seconds(ParameterKind::Rest, 15, 120, 60, 15))
+            .sound(Sound::Bell)
+            .difficulty(Difficulty::Medium)
+            .icon('🥊')
+            .build(),
+        Template::builder()
+            .name("HiiT")
+            .item(ItemList::Tabata.into())
+            .rounds(rounds(8, 16))
+            .work(seconds(ParameterKind::Work, 10, 60, 20, 5))
+            .rest(seconds(ParameterKind::Rest, 5, 60, 10, 5))
+            .sound(Sound::Beep)
+            .difficulty(Difficulty::Medium)
+            .icon('🧨')
+            .build(),
+    ];
+    templates.sort_by_key(|t| Natural::str(t.name().to_string()));
+    templates
+});
+
+pub static PROGRAMS: std::sync::LazyLock<Vec<Program>> = std::sync::LazyLock::new(|| {
+    let planned = |weekday,
+                   slug: &str,
+                   values: &[(ParameterKind, u64)],
+                   progression: &[(ParameterKind, i64)]| {
+        Planned::builder()
+            .weekday(weekday)
+            .slug(slug)
+            .values(values.iter().copied().collect())
+            .progression(progression.iter().copied().collect::<HashMap<_, _>>())
+            .build()
+    };
+    let mut programs = vec![
+        Program::builder()
+            .name("6-week boxing conditioning")
+            .description("Longer rounds and shorter rests week after week")
+            .weeks(6)
+            .days(vec![
+                planned(
+                    Weekday::Monday,
+                    "boxing-rounds",
+                    &[(ParameterKind::Rounds, 3), (ParameterKind::Work, 120)],
+                    &[(ParameterKind::Rounds, 1)],
+                ),
+                planned(
+                    Weekday::Wednesday,
+                    "hiit",
+                    &[(ParameterKind::Rounds, 6)],
+                    &[(ParameterKind::Rounds, 1)],
+                ),
+                planned(
+                    Weekday::Friday,
+                    "boxing-rounds",
+                    &[(ParameterKind::Rounds, 4), (ParameterKind::Rest, 90)],
+                    &[(ParameterKind::Work, 30), (ParameterKind::Rest, -15)],
+                ),
+            ])
+            .icon('🥊')
+            .build(),
+        Program::builder()
+            .name("4-week HiiT starter")
+            .description("Three short sessions a week to build up to a full tabata")
+            .weeks(4)
+            .days(vec![
+                planned(
+                    Weekday::Tuesday,
+                    "hiit",
+                    &[(ParameterKind::Rounds, 4), (ParameterKind::Rest, 20)],
+                    &[(ParameterKind::Rounds, 1), (ParameterKind::Rest, -5)],
+                ),
+                planned(
+                    Weekday::Thursday,
+                    "workout-rounds",
+                    &[(ParameterKind::Rounds, 3)],
+                    &[(ParameterKind::Rounds, 1)],
+                ),
+                planned(
+                    Weekday::Saturday,
+                    "hiit",
+                    &[(ParameterKind::Rounds, 6)],
+                    &[(ParameterKind::Rounds, 1)],
+                ),
+            ])
+            .icon('🧨')
+            .build(),
+    ];
+    programs.sort_by_key(|p| Natural::str(p.name().to_string()));
+    programs
+});
+
+/// A default sequence, or a default template instantiated with `values`
+pub fn find(slug: &str, values: &Values) -> Option<Sequence> {
+    SEQUENCES
+        .iter()
+        .find(|sequence| sequence.slug() == slug)
+        .cloned()
+        .or_else(|| {
+            TEMPLATES
+                .iter()
+                .find(|template| template.slug() == slug)
+                .map(|template| template.instantiate(values))
+        })
+}
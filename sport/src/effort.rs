@@ -0,0 +1,110 @@
+use crate::tag::{Boxing, Difficulty, Mouvement, Tag, Tags};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Oxygen consumption at rest, in ml per kg per minute
+const RESTING_OXYGEN: f64 = 3.5;
+
+/// Metabolic equivalent of task: energy cost of an activity relative to sitting quietly
+///
+/// Stored in tenths so items stay hashable, serialized as a decimal number.
+#[derive(
+    Display, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[display("{:.1} MET", self.value())]
+#[serde(from = "f64", into = "f64")]
+pub struct Met(u16);
+
+impl From<f64> for Met {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from(value: f64) -> Self {
+        Self((value.max(0.0) * 10.0).round().min(f64::from(u16::MAX)) as u16)
+    }
+}
+
+impl From<Met> for f64 {
+    fn from(met: Met) -> Self {
+        met.value()
+    }
+}
+
+impl Met {
+    pub const REST: Self = Self(13);
+    pub const STRETCHING: Self = Self(25);
+    pub const WARM_UP: Self = Self(35);
+    pub const STATIONARY: Self = Self(38);
+    pub const MODERATE: Self = Self(50);
+    pub const STRENGTH: Self = Self(60);
+    pub const BOXING: Self = Self(78);
+    pub const VIGOROUS: Self = Self(80);
+
+    pub fn value(self) -> f64 {
+        f64::from(self.0) / 10.0
+    }
+    /// Rough estimation for items without an explicit value, the most intense tag wins
+    pub fn from_tags(tags: &Tags) -> Self {
+        if tags.contains(&Tag::Rest) || tags.contains(&Tag::Drink) {
+            return Self::REST;
+        }
+        tags.iter()
+            .filter_map(|tag| match tag {
+                Tag::HiiT => Some(Self::VIGOROUS),
+                Tag::WarmUp => Some(Self::WARM_UP),
+                Tag::Boxing(Boxing::Round) => Some(Self::BOXING),
+                Tag::Boxing(_) => Some(Self::MODERATE),
+                Tag::Mouvement(Mouvement::Stretching) => Some(Self::STRETCHING),
+                Tag::Mouvement(Mouvement::Stationary | Mouvement::Balance) => {
+                    Some(Self::STATIONARY)
+                }
+                Tag::Mouvement(Mouvement::Strength) => Some(Self::STRENGTH),
+                Tag::Mouvement(Mouvement::Stamina | Mouvement::Dynamic) => Some(Self::VIGOROUS),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(Self::MODERATE)
+    }
+    /// Same activity performed at another difficulty, `None` keeps the base value
+    #[must_use]
+    pub fn at(self, difficulty: Option<Difficulty>) -> Self {
+        difficulty.map_or(self, |difficulty| {
+            Self::from(self.value() * difficulty.intensity())
+        })
+    }
+    /// Estimated energy expenditure in kilocalories
+    pub fn calories(self, weight: f64, duration: std::time::Duration) -> f64 {
+        self.value() * RESTING_OXYGEN * weight / 200.0 * duration.as_secs_f64() / 60.0
+    }
+    /// Training load in MET-minutes
+    pub fn load(self, duration: std::time::Duration) -> f64 {
+        self.value() * duration.as_secs_f64() / 60.0
+    }
+}
+
+#[test]
+fn met_tests() {
+    use crate::item_list::ItemList;
+    use crate::workout::Workout;
+
+    assert_eq!(Met::from(7.84), Met::BOXING);
+    assert_eq!(Met::BOXING.to_string(), "7.8 MET");
+    assert_eq!(serde_json::to_string(&Met::BOXING).unwrap(), "7.8");
+    assert_eq!(serde_json::from_str::<Met>("7.8").unwrap(), Met::BOXING);
+
+    let rest: crate::item::Item = ItemList::Rest.into();
+    assert_eq!(rest.met(), Met::REST);
+    let round: crate::item::Item = ItemList::BoxingRound.into();
+    assert_eq!(round.met(), Met::BOXING);
+    assert_eq!(Met::BOXING.at(None), Met::BOXING);
+    assert!(Met::BOXING.at(Some(Difficulty::Elite)) > Met::BOXING.at(Some(Difficulty::Easy)));
+
+    let hour = std::time::Duration::from_secs(3600);
+    assert!((Met::from(1.0).calories(80.0, hour) - 84.0).abs() < 1e-9);
+    assert!((Met::BOXING.load(hour) - 468.0).abs() < 1e-9);
+
+    let workout = Workout::builder()
+        .item(round)
+        .stopwatch(std::time::Duration::from_secs(180))
+        .difficulty(Difficulty::Hard)
+        .build();
+    assert!(workout.calories(70.0) > Met::BOXING.calories(70.0, *workout.duration()));
+}
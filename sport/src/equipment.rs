@@ -0,0 +1,152 @@
+use crate::errors::ErrorKind;
+use crate::item::Item;
+use crate::item_list::ItemList;
+use crate::sequence::Sequence;
+use crate::tag::{Equipment, Tag};
+use derive_more::{Deref, DerefMut, From, IntoIterator};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+/// Equipment a user has at hand
+#[derive(
+    Default,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Deref,
+    DerefMut,
+    IntoIterator,
+    From,
+    Serialize,
+    Deserialize,
+)]
+#[serde(transparent)]
+pub struct EquipmentProfile(Vec<Equipment>);
+
+impl EquipmentProfile {
+    pub fn all() -> Self {
+        Self(Equipment::VARIANTS.to_vec())
+    }
+    pub fn toggle(&mut self, equipment: Equipment) {
+        if self.0.contains(&equipment) {
+            self.0.retain(|e| *e != equipment);
+        } else {
+            self.0.push(equipment);
+        }
+    }
+    pub fn allows(&self, item: &Item) -> bool {
+        item.equipment()
+            .iter()
+            .all(|equipment| self.0.contains(equipment))
+    }
+    pub fn feasible(&self, sequence: &Sequence) -> bool {
+        sequence.iter().all(|workout| self.allows(workout.item()))
+    }
+    pub fn items(&self) -> Vec<Item> {
+        ItemList::items()
+            .into_iter()
+            .filter(|item| self.allows(item))
+            .collect_vec()
+    }
+    /// Sequences that can be done as is or once their exercises are substituted
+    pub fn sequences<'a>(&self, sequences: &'a [Sequence]) -> Vec<&'a Sequence> {
+        sequences
+            .iter()
+            .filter(|sequence| self.adapt(sequence).is_ok())
+            .collect_vec()
+    }
+    /// The item itself when possible, or the feasible item sharing the most body and mouvement tags
+    pub fn substitute(&self, item: &Item) -> Option<Item> {
+        if self.allows(item) {
+            return Some(item.clone());
+        }
+        let shared = |candidate: &Item| {
+            candidate
+                .tags()
+                .iter()
+                .filter(|tag| matches!(tag, Tag::Body(_) | Tag::Mouvement(_)))
+                .filter(|tag| item.tags().contains(tag))
+                .map(|tag| if matches!(tag, Tag::Body(_)) { 2 } else { 1 })
+                .sum::<usize>()
+        };
+        self.items()
+            .into_iter()
+            .filter(|candidate| !candidate.is_rest() && candidate != item)
+            .map(|candidate| (shared(&candidate), candidate))
+            .filter(|(score, _)| *score > 0)
+            .max_by(|(first, first_item), (second, second_item)| {
+                first
+                    .cmp(second)
+                    .then_with(|| {
+                        (first_item.level() == item.level())
+                            .cmp(&(second_item.level() == item.level()))
+                    })
+                    .then_with(|| second_item.name().cmp(first_item.name()))
+            })
+            .map(|(_, candidate)| candidate)
+    }
+    /// Replace every unavailable exercise of a sequence by its substitute
+    pub fn adapt(&self, sequence: &Sequence) -> Result<Sequence, ErrorKind> {
+        let mut substitutes = sequence
+            .iter()
+            .map(|workout| {
+                self.substitute(workout.item())
+                    .ok_or_else(|| ErrorKind::MissingEquipment(workout.item().name().to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+        let mut adapted = sequence.clone();
+        adapted.apply(|workout| {
+            if let Some(item) = substitutes.next() {
+                workout.set_item(item);
+            }
+        });
+        Ok(adapted)
+    }
+}
+
+#[test]
+fn equipment_profile_tests() {
+    use crate::sound::Sound;
+    use time::ext::NumericalStdDuration;
+
+    let bodyweight = EquipmentProfile::default();
+    let rope = EquipmentProfile::from(vec![Equipment::JumpRope]);
+    let jump_rope: Item = ItemList::JumpRope.into();
+    let box_jump: Item = ItemList::BoxJump.into();
+
+    assert!(!bodyweight.allows(&jump_rope));
+    assert!(rope.allows(&jump_rope));
+    assert!(EquipmentProfile::all().allows(&box_jump));
+    assert!(
+        bodyweight
+            .items()
+            .iter()
+            .all(|item| item.equipment().is_empty())
+    );
+    assert_eq!(
+        EquipmentProfile::all().items().len(),
+        ItemList::items().len()
+    );
+
+    let substitute = bodyweight.substitute(&box_jump).unwrap();
+    assert!(substitute.equipment().is_empty());
+    assert!(substitute.tags().contains(&crate::tag::Body::Core.into()));
+
+    let sequence = Sequence::simple()
+        .name("rope")
+        .workouts(&[
+            ItemList::JumpRope.workout(30.std_seconds()),
+            ItemList::BoxJump.workout(30.std_seconds()),
+        ])
+        .sound(&Sound::Silent)
+        .call();
+    assert!(!bodyweight.feasible(&sequence));
+    let adapted = bodyweight.adapt(&sequence).unwrap();
+    assert!(bodyweight.feasible(&adapted));
+    assert_eq!(adapted.total(), sequence.total());
+    assert_eq!(rope.adapt(&sequence).unwrap()[0].item(), &jump_rope);
+}
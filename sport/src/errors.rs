@@ -12,6 +12,24 @@ pub enum ErrorKind {
     // SoundError(String),
     #[error("Invalid sequence")]
     InvalidSequence,
+    #[error("Invalid combination: {0}")]
+    InvalidCombo(String),
+    #[error("Invalid constraints: {0}")]
+    InvalidConstraints(String),
+    #[error("No exercise matches the constraints")]
+    NoExercise,
+    #[error("No exercise works the {0} body part")]
+    UncoveredBody(crate::tag::Body),
+    #[error("Cannot avoid consecutive exercises working the same body part")]
+    Unbalanced,
+    #[error("No substitute without equipment for {0}")]
+    MissingEquipment(String),
+    #[error("Invalid heart rate file: {0}")]
+    InvalidHeartRate(String),
+    #[error("No heart rate reading during the session")]
+    HeartRateOutsideSession,
+    #[error("Invalid CSV at row {0}: {1}")]
+    InvalidCsv(usize, String),
 }
 
 // impl From<js_sys::Error> for ErrorKind {
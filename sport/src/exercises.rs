@@ -1,3 +1,5 @@
+use crate::combo::Combo;
+use crate::errors::ErrorKind;
 use crate::item::Item;
 use crate::item_list::ItemList;
 use crate::tag::Tag;
@@ -24,6 +26,13 @@ impl Exercises {
     pub fn from_strings(names: Vec<&str>, icon: Option<char>) -> Self {
         Self::Names(names.into_iter().map(str::to_string).collect_vec(), icon)
     }
+    pub fn from_combos(notations: &[&str]) -> Result<Self, ErrorKind> {
+        let items = notations
+            .iter()
+            .map(|notation| notation.parse::<Combo>().map(Item::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::Items(items))
+    }
     pub fn from_items(items: Vec<Item>) -> Self {
         Self::Items(items)
     }
@@ -73,9 +82,24 @@ fn exercises_tests() {
     let exercises = Exercises::from_tags(&body_tags);
     assert_eq!(
         exercises.len(),
-        3,
+        11,
         "exercises for tags {:?} : {}",
         body_tags,
         exercises
     );
 }
+
+#[test]
+fn exercises_combos_tests() {
+    use crate::tag::Boxing;
+    let exercises = Exercises::from_combos(&["1-2", "1-2-3-R"]).unwrap();
+    assert_eq!(
+        exercises.to_string(),
+        "Jab | Cross (1-2), Jab | Cross | Lead Hook | Roll (1-2-3-R)"
+    );
+    let Exercises::Items(items) = exercises else {
+        panic!("combos should be converted to items");
+    };
+    assert!(items[1].tags().contains(&Boxing::Roll.into()));
+    assert!(Exercises::from_combos(&["1-2", "X"]).is_err());
+}
@@ -0,0 +1,471 @@
+use crate::program::{Day, Enrollment, Planned};
+use crate::session::Session;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{OffsetDateTime, PrimitiveDateTime, Time};
+
+/// Seconds between the unix epoch and the FIT epoch, 1989-12-31T00:00:00Z
+const FIT_EPOCH: i64 = 631_065_600;
+
+/// FIT profile version 21.40
+const FIT_PROFILE: u16 = 2140;
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn kilocalories(calories: f64) -> u16 {
+    calories.round().clamp(0.0, f64::from(u16::MAX)) as u16
+}
+
+fn rfc3339(timestamp: i64) -> String {
+    OffsetDateTime::from_unix_timestamp(timestamp)
+        .unwrap_or(OffsetDateTime::UNIX_EPOCH)
+        .format(&Rfc3339)
+        .unwrap_or_default()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Training Center XML activity with one lap per workout
+pub fn tcx(session: &Session) -> String {
+    let mut xml = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<TrainingCenterDatabase xmlns=\"http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2\">\n",
+        "  <Activities>\n",
+    ));
+    xml.push_str(&format!(
+        "    <Activity Sport=\"{}\">\n      <Id>{}</Id>\n",
+        session.activity().tcx(),
+        rfc3339(session.started())
+    ));
+    for (start, lap) in session.intervals() {
+        xml.push_str(&format!(
+            concat!(
+                "      <Lap StartTime=\"{}\">\n",
+                "        <TotalTimeSeconds>{}</TotalTimeSeconds>\n",
+                "        <DistanceMeters>0</DistanceMeters>\n",
+                "        <Calories>{}</Calories>\n",
+                "        <Intensity>{}</Intensity>\n",
+                "        <TriggerMethod>Manual</TriggerMethod>\n",
+                "        <Notes>{}</Notes>\n",
+                "      </Lap>\n",
+            ),
+            rfc3339(start),
+            lap.duration().as_secs(),
+            kilocalories(lap.calories()),
+            if lap.is_rest() { "Resting" } else { "Active" },
+            escape(lap.name()),
+        ));
+    }
+    xml.push_str(&format!(
+        "      <Notes>{}</Notes>\n    </Activity>\n  </Activities>\n</TrainingCenterDatabase>\n",
+        escape(session.name())
+    ));
+    xml
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Value<'a> {
+    Enum(u8),
+    U16(u16),
+    U32(u32),
+    U32z(u32),
+    Str(&'a str),
+}
+
+impl Value<'_> {
+    fn base_type(&self) -> u8 {
+        match self {
+            Self::Enum(_) => 0x00,
+            Self::U16(_) => 0x84,
+            Self::U32(_) => 0x86,
+            Self::U32z(_) => 0x8C,
+            Self::Str(_) => 0x07,
+        }
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    fn size(&self) -> u8 {
+        match self {
+            Self::Enum(_) => 1,
+            Self::U16(_) => 2,
+            Self::U32(_) | Self::U32z(_) => 4,
+            Self::Str(text) => text.len() as u8 + 1,
+        }
+    }
+    fn write(&self, data: &mut Vec<u8>) {
+        match self {
+            Self::Enum(value) => data.push(*value),
+            Self::U16(value) => data.extend(value.to_le_bytes()),
+            Self::U32(value) | Self::U32z(value) => data.extend(value.to_le_bytes()),
+            Self::Str(text) => {
+                data.extend(text.as_bytes());
+                data.push(0);
+            }
+        }
+    }
+}
+
+/// Strings are null terminated and their size must fit a byte
+fn truncate(text: &str) -> &str {
+    let mut end = text.len().min(254);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+fn fit_time(timestamp: i64) -> u32 {
+    u32::try_from(timestamp - FIT_EPOCH).unwrap_or_default()
+}
+
+fn milliseconds(duration: std::time::Duration) -> u32 {
+    u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+pub(crate) fn crc(data: &[u8]) -> u16 {
+    const TABLE: [u16; 16] = [
+        0x0000, 0xCC01, 0xD801, 0x1400, 0xF001, 0x3C00, 0x2800, 0xE401, 0xA001, 0x6C00, 0x7800,
+        0xB401, 0x5000, 0x9C01, 0x8801, 0x4400,
+    ];
+    data.iter().fold(0, |crc, byte| {
+        let crc = (crc >> 4) ^ TABLE[usize::from(crc & 0xF)] ^ TABLE[usize::from(byte & 0xF)];
+        (crc >> 4) ^ TABLE[usize::from(crc & 0xF)] ^ TABLE[usize::from(byte >> 4)]
+    })
+}
+
+/// Single local message type, redefined whenever the layout changes
+#[derive(Default)]
+struct FitWriter {
+    data: Vec<u8>,
+    definition: Vec<u8>,
+}
+
+impl FitWriter {
+    fn message(&mut self, global: u16, fields: &[(u8, Value)]) {
+        let mut definition = vec![0x40, 0, 0];
+        definition.extend(global.to_le_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        definition.push(fields.len() as u8);
+        for (number, value) in fields {
+            definition.extend([*number, value.size(), value.base_type()]);
+        }
+        if definition != self.definition {
+            self.data.extend(&definition);
+            self.definition = definition;
+        }
+        self.data.push(0);
+        for (_, value) in fields {
+            value.write(&mut self.data);
+        }
+    }
+    fn finish(self) -> Vec<u8> {
+        let mut file = vec![14, 0x20];
+        file.extend(FIT_PROFILE.to_le_bytes());
+        file.extend(
+            u32::try_from(self.data.len())
+                .unwrap_or(u32::MAX)
+                .to_le_bytes(),
+        );
+        file.extend(b".FIT");
+        file.extend(crc(&file).to_le_bytes());
+        file.extend(self.data);
+        file.extend(crc(&file).to_le_bytes());
+        file
+    }
+}
+
+/// FIT activity with one lap per workout, item names live in the embedded workout steps
+pub fn fit(session: &Session) -> Vec<u8> {
+    use Value::{Enum, Str, U16, U32, U32z};
+
+    let intervals = session.intervals();
+    let start = fit_time(session.started());
+    let end = fit_time(session.ended());
+    let total = milliseconds(session.duration());
+    let (sport, sub_sport) = session.activity().fit();
+    let count = u16::try_from(intervals.len()).unwrap_or(u16::MAX);
+    let mut fit = FitWriter::default();
+
+    // file_id: activity made by a development manufacturer
+    fit.message(
+        0,
+        &[
+            (0, Enum(4)),
+            (1, U16(255)),
+            (2, U16(0)),
+            (3, U32z(1)),
+            (4, U32(start)),
+        ],
+    );
+    // workout and its steps
+    fit.message(
+        26,
+        &[
+            (4, Enum(sport)),
+            (11, Enum(sub_sport)),
+            (6, U16(count)),
+            (8, Str(truncate(session.name()))),
+        ],
+    );
+    for (index, (_, lap)) in (0..count).zip(&intervals) {
+        fit.message(
+            27,
+            &[
+                (254, U16(index)),
+                (0, Str(truncate(lap.name()))),
+                (1, Enum(0)),
+                (2, U32(milliseconds(lap.duration()))),
+                (3, Enum(2)),
+                (7, Enum(u8::from(lap.is_rest()))),
+            ],
+        );
+    }
+    // timer start
+    fit.message(21, &[(253, U32(start)), (0, Enum(0)), (1, Enum(0))]);
+    for (index, (lap_start, lap)) in (0..count).zip(&intervals) {
+        let (sport, sub_sport) = lap.activity().fit();
+        let duration = milliseconds(lap.duration());
+        let lap_start = fit_time(*lap_start);
+        let lap_end = lap_start.saturating_add(duration / 1000);
+        fit.message(
+            19,
+            &[
+                (254, U16(index)),
+                (253, U32(lap_end)),
+                (0, Enum(9)),
+                (1, Enum(1)),
+                (2, U32(lap_start)),
+                (7, U32(duration)),
+                (8, U32(duration)),
+                (11, U16(kilocalories(lap.calories()))),
+                (23, Enum(u8::from(lap.is_rest()))),
+                (24, Enum(0)),
+                (25, Enum(sport)),
+                (39, Enum(sub_sport)),
+                (71, U16(index)),
+            ],
+        );
+    }
+    // timer stop all
+    fit.message(21, &[(253, U32(end)), (0, Enum(0)), (1, Enum(4))]);
+    fit.message(
+        18,
+        &[
+            (254, U16(0)),
+            (253, U32(end)),
+            (0, Enum(8)),
+            (1, Enum(1)),
+            (2, U32(start)),
+            (5, Enum(sport)),
+            (6, Enum(sub_sport)),
+            (7, U32(total)),
+            (8, U32(total)),
+            (11, U16(kilocalories(session.calories()))),
+            (25, U16(0)),
+            (26, U16(count)),
+            (28, Enum(0)),
+        ],
+    );
+    fit.message(
+        34,
+        &[
+            (253, U32(end)),
+            (0, U32(total)),
+            (1, U16(1)),
+            (2, Enum(0)),
+            (3, Enum(26)),
+            (4, Enum(1)),
+        ],
+    );
+    fit.finish()
+}
+
+/// Escape a text value, RFC 5545 section 3.3.11
+fn ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold content lines longer than 75 octets, RFC 5545 section 3.1
+fn ics_line(calendar: &mut String, line: &str) {
+    let mut length = 0;
+    for character in line.chars() {
+        if length + character.len_utf8() > 75 {
+            calendar.push_str("\r\n ");
+            length = 1;
+        }
+        calendar.push(character);
+        length += character.len_utf8();
+    }
+    calendar.push_str("\r\n");
+}
+
+/// iCalendar with one event per planned session of a program, at a local time of day
+///
+/// `link` gives the url of the timer of a planned session.
+pub fn ics(
+    enrollment: &Enrollment,
+    at: Time,
+    stamp: i64,
+    link: impl Fn(&Planned, Day) -> String,
+) -> String {
+    let local = format_description!("[year][month][day]T[hour][minute][second]");
+    let utc = format_description!("[year][month][day]T[hour][minute][second]Z");
+    let stamp = OffsetDateTime::from_unix_timestamp(stamp)
+        .unwrap_or(OffsetDateTime::UNIX_EPOCH)
+        .format(utc)
+        .unwrap_or_default();
+    let mut calendar = String::new();
+    let mut line = |line: &str| ics_line(&mut calendar, line);
+    line("BEGIN:VCALENDAR");
+    line("VERSION:2.0");
+    line("PRODID:-//sport//program//EN");
+    line("CALSCALE:GREGORIAN");
+    let Some(program) = enrollment.program() else {
+        line("END:VCALENDAR");
+        return calendar;
+    };
+    line(&format!("X-WR-CALNAME:{}", ics_text(program.name())));
+    for day in program.schedule() {
+        let (Some(planned), Some(sequence), Some(date)) = (
+            program.planned(day.weekday),
+            program.sequence(day),
+            enrollment.date(day),
+        ) else {
+            continue;
+        };
+        let url = link(planned, day);
+        let items = sequence
+            .unique_items()
+            .iter()
+            .map(|item| format!("- {}", item.name()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        line("BEGIN:VEVENT");
+        line(&format!(
+            "UID:{}-{}-{}@sport",
+            program.slug(),
+            enrollment.started(),
+            date
+        ));
+        line(&format!("DTSTAMP:{stamp}"));
+        line(&format!(
+            "DTSTART:{}",
+            PrimitiveDateTime::new(date, at)
+                .format(local)
+                .unwrap_or_default()
+        ));
+        line(&format!("DURATION:PT{}S", sequence.total().as_secs()));
+        line(&format!("SUMMARY:{}", ics_text(sequence.name())));
+        line(&format!(
+            "DESCRIPTION:{}",
+            ics_text(&format!("{items}\n\n{url}"))
+        ));
+        line(&format!("URL:{url}"));
+        line("END:VEVENT");
+    }
+    line("END:VCALENDAR");
+    calendar
+}
+
+#[test]
+fn tcx_tests() {
+    use crate::defaults::SEQUENCES;
+
+    let sequence = SEQUENCES.first().unwrap();
+    let session = Session::record(sequence, 70.0, 1_700_000_000);
+    let xml = tcx(&session);
+    assert!(xml.contains("<Id>2023-11-14T22:13:20Z</Id>"));
+    assert_eq!(xml.matches("<Lap ").count(), sequence.len());
+    assert!(xml.contains(&format!(
+        "<Notes>{}</Notes>",
+        escape(sequence.first().unwrap().item().name())
+    )));
+    assert_eq!(escape("Tom & <Jerry>"), "Tom &amp; &lt;Jerry&gt;");
+}
+
+#[test]
+fn fit_tests() {
+    use crate::defaults::SEQUENCES;
+    use fitparser::profile::MesgNum;
+
+    let sequence = SEQUENCES
+        .iter()
+        .find(|sequence| sequence.name().starts_with("Boxing"))
+        .unwrap();
+    let session = Session::record(sequence, 70.0, 1_700_000_000);
+    let bytes = fit(&session);
+    assert_eq!(&bytes[8..12], b".FIT");
+    // the crc of a file followed by its crc is zero
+    assert_eq!(crc(&bytes), 0);
+
+    let records = fitparser::from_bytes(&bytes).unwrap();
+    let count = |kind| records.iter().filter(|r| r.kind() == kind).count();
+    assert_eq!(count(MesgNum::Lap), sequence.len());
+    assert_eq!(count(MesgNum::WorkoutStep), sequence.len());
+    assert_eq!(count(MesgNum::Session), 1);
+    let field = |kind, name: &str| {
+        records
+            .iter()
+            .find(|r| r.kind() == kind)
+            .and_then(|r| r.fields().iter().find(|f| f.name() == name))
+            .map(|f| f.value().to_string())
+            .unwrap()
+    };
+    assert_eq!(field(MesgNum::Session, "sport"), "boxing");
+    assert_eq!(
+        field(MesgNum::WorkoutStep, "wkt_step_name"),
+        sequence.first().unwrap().item().name()
+    );
+}
+
+#[test]
+fn ics_tests() {
+    use crate::defaults::PROGRAMS;
+    use time::macros::{date, time};
+
+    let program = PROGRAMS.first().unwrap();
+    let enrollment = Enrollment::new(program, date!(2024 - 01 - 03));
+    let calendar = ics(&enrollment, time!(18:30), 1_700_000_000, |planned, day| {
+        format!(
+            "https://sport.example/sequence?slug={}&week={}",
+            planned.slug(),
+            day.week
+        )
+    });
+    assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(
+        calendar.matches("BEGIN:VEVENT").count(),
+        program.schedule().len()
+    );
+    assert!(calendar.contains("DTSTAMP:20231114T221320Z"));
+
+    let first = Day {
+        week: 0,
+        weekday: program.days()[0].weekday(),
+    };
+    let sequence = program.sequence(first).unwrap();
+    let start = enrollment.date(first).unwrap();
+    assert!(calendar.contains(&format!(
+        "DTSTART:{}T183000",
+        start.format(format_description!("[year][month][day]")).unwrap()
+    )));
+    assert!(calendar.contains(&format!("DURATION:PT{}S", sequence.total().as_secs())));
+    let unfolded = calendar.replace("\r\n ", "");
+    assert!(unfolded.contains(&format!("SUMMARY:{}", ics_text(sequence.name()))));
+    assert!(unfolded.contains(&format!("- {}", sequence.unique_items()[0].name())));
+    assert!(
+        calendar
+            .lines()
+            .all(|line| line.trim_end_matches('\r').len() <= 75)
+    );
+
+    assert_eq!(ics_text("a,b;c\nd"), "a\\,b\\;c\\nd");
+}
@@ -0,0 +1,277 @@
+use crate::equipment::EquipmentProfile;
+use crate::errors::ErrorKind;
+use crate::item::Item;
+use crate::item_list::ItemList;
+use crate::sequence::Sequence;
+use crate::sound::Sound;
+use crate::tag::{Body, Difficulty, Tag};
+use crate::workout::Workout;
+use bon::Builder;
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use strum::VariantArray;
+
+pub const GENERATOR_ICON: char = '🎲';
+pub const GENERATOR_ATTEMPTS: usize = 64;
+
+/// Constraints of a random workout, turned into a balanced sequence by `generate`
+#[derive(Clone, Debug, Builder)]
+pub struct Generator {
+    #[builder(default = "Random workout".to_string(), into)]
+    name: String,
+    /// Time budget, work and rest included
+    total: std::time::Duration,
+    /// Equipment at hand, exercises needing anything else are left out
+    #[builder(default, into)]
+    equipment: EquipmentProfile,
+    /// Body parts each worked by at least one exercise
+    #[builder(default, into)]
+    body: Vec<Body>,
+    #[builder(default = Difficulty::Easy)]
+    min_difficulty: Difficulty,
+    #[builder(default = Difficulty::Elite)]
+    max_difficulty: Difficulty,
+    #[builder(default = std::time::Duration::from_secs(30))]
+    work: std::time::Duration,
+    /// Work to rest ratio, (2, 1) rests half as long as it works
+    #[builder(default = (2, 1))]
+    ratio: (u32, u32),
+    #[builder(default)]
+    sound: Sound,
+    seed: Option<u64>,
+}
+
+fn body_parts(item: &Item) -> Vec<Body> {
+    item.tags()
+        .iter()
+        .filter_map(|tag| match tag {
+            Tag::Body(body) => Some(*body),
+            _ => None,
+        })
+        .collect_vec()
+}
+
+fn overlaps(first: &Item, second: &Item) -> bool {
+    first == second
+        || body_parts(first)
+            .iter()
+            .any(|body| body_parts(second).contains(body))
+}
+
+impl Generator {
+    pub fn rest(&self) -> std::time::Duration {
+        let (work, rest) = self.ratio;
+        self.work * rest / work.max(1)
+    }
+    /// Number of work intervals fitting in the time budget
+    pub fn intervals(&self) -> usize {
+        let rest = self.rest();
+        let interval = (self.work + rest).as_secs();
+        if interval == 0 {
+            return 0;
+        }
+        usize::try_from((self.total + rest).as_secs() / interval).unwrap_or(usize::MAX)
+    }
+    /// Exercises allowed by the equipment and difficulty constraints, unrated exercises count as easy
+    pub fn candidates(&self) -> Vec<Item> {
+        ItemList::items()
+            .into_iter()
+            .filter(|item| {
+                !item.is_rest()
+                    && !item.tags().contains(&Tag::WarmUp)
+                    && item
+                        .tags()
+                        .iter()
+                        .any(|tag| matches!(tag, Tag::Body(_) | Tag::Mouvement(_)))
+            })
+            .filter(|item| item.level().unwrap_or(Difficulty::Easy) <= self.max_difficulty)
+            .filter(|item| self.equipment.allows(item))
+            .collect_vec()
+    }
+    fn validate(&self) -> Result<usize, ErrorKind> {
+        if self.ratio.0 == 0 {
+            return Err(ErrorKind::InvalidConstraints(
+                "work part of the ratio must not be zero".to_string(),
+            ));
+        }
+        if self.work.is_zero() {
+            return Err(ErrorKind::InvalidConstraints(
+                "work duration must not be zero".to_string(),
+            ));
+        }
+        if self.min_difficulty > self.max_difficulty {
+            return Err(ErrorKind::InvalidConstraints(format!(
+                "difficulty {} is above {}",
+                self.min_difficulty, self.max_difficulty
+            )));
+        }
+        let intervals = self.intervals();
+        let body = self.body.iter().unique().count();
+        if intervals == 0 || intervals < body {
+            return Err(ErrorKind::InvalidConstraints(format!(
+                "{}s cannot fit {} intervals of {}s",
+                self.total.as_secs(),
+                body.max(1),
+                self.work.as_secs()
+            )));
+        }
+        Ok(intervals)
+    }
+    fn pick<R: Rng + ?Sized>(&self, intervals: usize, rng: &mut R) -> Result<Vec<Item>, ErrorKind> {
+        let mut candidates = self.candidates();
+        if candidates.is_empty() {
+            return Err(ErrorKind::NoExercise);
+        }
+        candidates.shuffle(rng);
+        let mut picked: Vec<Item> = Vec::with_capacity(intervals);
+        for body in self.body.iter().unique() {
+            if picked.iter().any(|item| body_parts(item).contains(body)) {
+                continue;
+            }
+            let item = candidates
+                .iter()
+                .find(|item| !picked.contains(item) && body_parts(item).contains(body))
+                .ok_or(ErrorKind::UncoveredBody(*body))?;
+            picked.push(item.clone());
+        }
+        let others = candidates
+            .iter()
+            .filter(|item| !picked.contains(item))
+            .cloned()
+            .collect_vec();
+        let mut fillers = others.iter().chain(candidates.iter()).cycle();
+        while picked.len() < intervals {
+            let Some(item) = fillers.next() else {
+                break;
+            };
+            picked.push(item.clone());
+        }
+        Ok(picked)
+    }
+    /// Order exercises so that two consecutive ones never work the same body part
+    fn balance<R: Rng + ?Sized>(items: &[Item], rng: &mut R) -> Result<Vec<Item>, ErrorKind> {
+        for _ in 0..GENERATOR_ATTEMPTS {
+            let mut left = items.to_vec();
+            let mut ordered: Vec<Item> = Vec::with_capacity(items.len());
+            while !left.is_empty() {
+                let eligible = left
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| ordered.last().is_none_or(|last| !overlaps(last, item)))
+                    .map(|(index, _)| index)
+                    .collect_vec();
+                let Some(index) = eligible.choose(rng) else {
+                    break;
+                };
+                ordered.push(left.swap_remove(*index));
+            }
+            if left.is_empty() {
+                return Ok(ordered);
+            }
+        }
+        Err(ErrorKind::Unbalanced)
+    }
+    pub fn generate(&self) -> Result<Sequence, ErrorKind> {
+        let intervals = self.validate()?;
+        let seed = self.seed.unwrap_or_else(rand::random);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let items = Self::balance(&self.pick(intervals, &mut rng)?, &mut rng)?;
+        let workouts = items
+            .iter()
+            .map(|item| {
+                let floor = item
+                    .level()
+                    .unwrap_or(Difficulty::Easy)
+                    .max(self.min_difficulty);
+                let difficulty = Difficulty::VARIANTS
+                    .iter()
+                    .filter(|difficulty| (floor..=self.max_difficulty).contains(difficulty))
+                    .copied()
+                    .collect_vec()
+                    .choose(&mut rng)
+                    .copied()
+                    .unwrap_or(floor);
+                item.difficulty(self.work, difficulty)
+            })
+            .collect_vec();
+        let rest = self.rest();
+        let workouts = if rest.is_zero() {
+            workouts
+        } else {
+            itertools::intersperse(workouts, Workout::rest(rest)).collect_vec()
+        };
+        Ok(Sequence::simple()
+            .name(&self.name)
+            .description(&format!("Generated workout (seed {seed})"))
+            .workouts(&workouts)
+            .sound(&self.sound)
+            .difficulty(self.max_difficulty)
+            .icon(GENERATOR_ICON)
+            .call())
+    }
+}
+
+#[test]
+fn generator_tests() {
+    use time::ext::NumericalStdDuration;
+    let generator = Generator::builder()
+        .total(10.std_minutes())
+        .body(vec![Body::Core, Body::Full, Body::Obliques, Body::Hip])
+        .max_difficulty(Difficulty::Medium)
+        .work(40.std_seconds())
+        .ratio((2, 1))
+        .seed(11)
+        .build();
+    assert_eq!(generator.rest(), 20.std_seconds());
+    assert_eq!(generator.intervals(), 10);
+
+    let sequence = generator.generate().unwrap();
+    assert_eq!(sequence, generator.generate().unwrap());
+    assert!(sequence.total() <= 10.std_minutes());
+    assert_eq!(sequence.workout_total(), 400.std_seconds());
+    let items = sequence
+        .iter()
+        .filter(|workout| !workout.is_rest())
+        .map(Workout::item)
+        .collect_vec();
+    assert_eq!(items.len(), 10);
+    for body in [Body::Core, Body::Full, Body::Obliques, Body::Hip] {
+        assert!(items.iter().any(|item| body_parts(item).contains(&body)));
+    }
+    for (previous, next) in items.iter().tuple_windows() {
+        assert!(!overlaps(previous, next), "{previous} then {next}");
+    }
+    assert!(items.iter().all(|item| {
+        item.equipment().is_empty()
+            && item.level().unwrap_or(Difficulty::Easy) <= Difficulty::Medium
+    }));
+}
+
+#[test]
+fn generator_errors_tests() {
+    use time::ext::NumericalStdDuration;
+    let too_short = Generator::builder().total(10.std_seconds()).build();
+    assert!(matches!(
+        too_short.generate(),
+        Err(ErrorKind::InvalidConstraints(_))
+    ));
+    let uncovered = Generator::builder()
+        .total(10.std_minutes())
+        .body(vec![Body::Triceps])
+        .build();
+    assert!(matches!(
+        uncovered.generate(),
+        Err(ErrorKind::UncoveredBody(Body::Triceps))
+    ));
+    let inverted = Generator::builder()
+        .total(10.std_minutes())
+        .min_difficulty(Difficulty::Hard)
+        .max_difficulty(Difficulty::Easy)
+        .build();
+    assert!(matches!(
+        inverted.generate(),
+        Err(ErrorKind::InvalidConstraints(_))
+    ));
+}
@@ -0,0 +1,430 @@
+use crate::errors::ErrorKind;
+use crate::session::Session;
+use derive_more::{Deref, From, IntoIterator};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+/// CSV times below this are seconds elapsed since the start of the session
+const ELAPSED_LIMIT: i64 = 1_000_000_000;
+
+/// One heart rate reading at a unix timestamp in seconds
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sample {
+    pub time: i64,
+    pub bpm: u8,
+}
+
+/// Readings sorted by time
+#[derive(
+    Default, Clone, Debug, PartialEq, Eq, Deref, IntoIterator, From, Serialize, Deserialize,
+)]
+pub struct HeartRate(Vec<Sample>);
+
+/// Heart rate over one lap of a session
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LapHeartRate {
+    pub name: String,
+    pub rest: bool,
+    pub average: Option<u8>,
+    pub max: Option<u8>,
+}
+
+fn invalid(reason: impl std::fmt::Display) -> ErrorKind {
+    ErrorKind::InvalidHeartRate(reason.to_string())
+}
+
+fn parse_time(text: &str) -> Result<i64, ErrorKind> {
+    OffsetDateTime::parse(text.trim(), &Rfc3339)
+        .map(OffsetDateTime::unix_timestamp)
+        .map_err(|err| invalid(format!("{text}: {err}")))
+}
+
+/// Tag name without its namespace prefix and attributes, `Value` in `<ns3:Value a="b">`
+fn local_name(tag: &str) -> &str {
+    let name = tag
+        .split(|character: char| character.is_whitespace() || matches!(character, '>' | '/'))
+        .next()
+        .unwrap_or_default();
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Text of each `tag` element, whatever its namespace and attributes
+fn elements<'a>(xml: &'a str, tag: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = xml;
+    std::iter::from_fn(move || {
+        loop {
+            let open = &rest[rest.find('<')? + 1..];
+            let end = open.find('>')?;
+            rest = &open[end + 1..];
+            if local_name(open) != tag || open[..end].ends_with('/') {
+                continue;
+            }
+            let content = rest;
+            let mut closed = 0;
+            loop {
+                let close = rest.find("</")?;
+                closed += close;
+                rest = &rest[close + 2..];
+                if local_name(rest) == tag {
+                    return Some(&content[..closed]);
+                }
+                closed += 2;
+            }
+        }
+    })
+}
+
+fn element<'a>(xml: &'a str, tag: &'a str) -> Option<&'a str> {
+    elements(xml, tag).next()
+}
+
+impl HeartRate {
+    /// Pick the parser from the file extension, `started` anchors CSV elapsed times
+    pub fn parse(name: &str, bytes: &[u8], started: i64) -> Result<Self, ErrorKind> {
+        let extension = name
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_lowercase())
+            .unwrap_or_default();
+        let text = || std::str::from_utf8(bytes).map_err(invalid);
+        match extension.as_str() {
+            "tcx" => Self::from_tcx(text()?),
+            "fit" => Self::from_fit(bytes),
+            "csv" => Self::from_csv(text()?, started),
+            _ => Err(invalid(format!("unsupported file {name}"))),
+        }
+    }
+    /// Trackpoints of a Training Center XML file
+    pub fn from_tcx(xml: &str) -> Result<Self, ErrorKind> {
+        let mut samples = Vec::new();
+        for trackpoint in elements(xml, "Trackpoint") {
+            let Some(bpm) = element(trackpoint, "HeartRateBpm")
+                .and_then(|heart_rate| element(heart_rate, "Value"))
+            else {
+                continue;
+            };
+            let time =
+                element(trackpoint, "Time").ok_or_else(|| invalid("trackpoint without time"))?;
+            samples.push(Sample {
+                time: parse_time(time)?,
+                bpm: bpm.trim().parse().map_err(invalid)?,
+            });
+        }
+        Ok(Self::sorted(samples))
+    }
+    /// `record` messages of a FIT activity
+    pub fn from_fit(bytes: &[u8]) -> Result<Self, ErrorKind> {
+        use fitparser::Value;
+        use fitparser::profile::MesgNum;
+
+        let mut samples = Vec::new();
+        for record in fitparser::from_bytes(bytes).map_err(invalid)? {
+            if record.kind() != MesgNum::Record {
+                continue;
+            }
+            let mut time = None;
+            let mut bpm = None;
+            for field in record.fields() {
+                match (field.name(), field.value()) {
+                    ("timestamp", Value::Timestamp(timestamp)) => {
+                        time = Some(timestamp.timestamp())
+                    }
+                    ("heart_rate", Value::UInt8(value)) => bpm = Some(*value),
+                    _ => {}
+                }
+            }
+            if let (Some(time), Some(bpm)) = (time, bpm) {
+                samples.push(Sample { time, bpm });
+            }
+        }
+        Ok(Self::sorted(samples))
+    }
+    /// `time,bpm` lines, times are RFC 3339, unix seconds or seconds since `started`
+    pub fn from_csv(csv: &str, started: i64) -> Result<Self, ErrorKind> {
+        let mut samples = Vec::new();
+        for (number, line) in csv.lines().enumerate() {
+            let Some((time, bpm)) = line.split_once([',', ';']) else {
+                continue;
+            };
+            let bpm = bpm.split([',', ';']).next().unwrap_or_default().trim();
+            let Ok(bpm) = bpm.parse() else {
+                // header
+                if number == 0 {
+                    continue;
+                }
+                return Err(invalid(format!(
+                    "line {}: {bpm} is not a heart rate",
+                    number + 1
+                )));
+            };
+            let time = match time.trim().parse::<i64>() {
+                Ok(seconds) if seconds < ELAPSED_LIMIT => started.saturating_add(seconds),
+                Ok(seconds) => seconds,
+                Err(_) => parse_time(time)?,
+            };
+            samples.push(Sample { time, bpm });
+        }
+        Ok(Self::sorted(samples))
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    fn sorted(mut samples: Vec<Sample>) -> Self {
+        samples.sort_by_key(|sample| sample.time);
+        Self(samples)
+    }
+    /// Readings within `[start, end)`
+    pub fn between(&self, start: i64, end: i64) -> &[Sample] {
+        let from = self.partition_point(|sample| sample.time < start);
+        let to = self.partition_point(|sample| sample.time < end);
+        &self[from..to]
+    }
+    /// Keep only the readings taken during the session
+    #[must_use]
+    pub fn during(&self, session: &Session) -> Self {
+        Self(self.between(session.started(), session.ended()).to_vec())
+    }
+    /// Average and maximum heart rate of every lap, rounds and rests alike
+    pub fn align(&self, session: &Session) -> Vec<LapHeartRate> {
+        session
+            .intervals()
+            .into_iter()
+            .map(|(start, lap)| {
+                let end = start.saturating_add_unsigned(lap.duration().as_secs());
+                let samples = self.between(start, end);
+                let sum: u64 = samples.iter().map(|sample| u64::from(sample.bpm)).sum();
+                let count = samples.len() as u64;
+                LapHeartRate {
+                    name: lap.name().to_string(),
+                    rest: lap.is_rest(),
+                    average: sum
+                        .checked_div(count)
+                        .and_then(|average| u8::try_from(average).ok()),
+                    max: samples.iter().map(|sample| sample.bpm).max(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn heart_rate_parse_tests() {
+    let tcx = r#"<Track>
+        <Trackpoint><Time>2023-11-14T22:13:21Z</Time><HeartRateBpm><Value>120</Value></HeartRateBpm></Trackpoint>
+        <Trackpoint><Time>2023-11-14T22:13:20Z</Time><HeartRateBpm><Value>110</Value></HeartRateBpm></Trackpoint>
+        <Trackpoint><Time>2023-11-14T22:13:22Z</Time></Trackpoint>
+    </Track>"#;
+    let heart_rate = HeartRate::parse("strap.TCX", tcx.as_bytes(), 0).unwrap();
+    assert_eq!(
+        heart_rate,
+        HeartRate::from(vec![
+            Sample {
+                time: 1_700_000_000,
+                bpm: 110
+            },
+            Sample {
+                time: 1_700_000_001,
+                bpm: 120
+            },
+        ])
+    );
+
+    // Garmin exports carry attributes and namespaces
+    let tcx = r#"<?xml version="1.0"?><ns3:Track>
+        <ns3:Trackpoint><ns3:Time>2023-11-14T22:13:20Z</ns3:Time>
+        <ns3:HeartRateBpm xsi:type="HeartRateInBeatsPerMinute_t"><ns3:Value>130</ns3:Value></ns3:HeartRateBpm>
+        <ns3:Extensions/></ns3:Trackpoint>
+    </ns3:Track>"#;
+    assert_eq!(
+        HeartRate::from_tcx(tcx).unwrap(),
+        HeartRate::from(vec![Sample {
+            time: 1_700_000_000,
+            bpm: 130
+        }])
+    );
+
+    let csv = "time,heart_rate\n0,100\n1,101\n1700000010,130\n2023-11-14T22:13:30Z;140\n";
+    let heart_rate = HeartRate::from_csv(csv, 1_700_000_000).unwrap();
+    assert_eq!(heart_rate.len(), 4);
+    assert_eq!(
+        heart_rate[1],
+        Sample {
+            time: 1_700_000_001,
+            bpm: 101
+        }
+    );
+    assert_eq!(
+        heart_rate[3],
+        Sample {
+            time: 1_700_000_010,
+            bpm: 140
+        }
+    );
+    assert!(HeartRate::from_csv("0,100\n1,fast\n", 0).is_err());
+    assert!(HeartRate::parse("strap.gpx", b"", 0).is_err());
+}
+
+#[test]
+fn heart_rate_fit_tests() {
+    // a minimal activity with two records: timestamp (253, uint32) and heart_rate (3, uint8)
+    let mut data = vec![0x40, 0, 0, 20, 0, 2, 253, 4, 0x86, 3, 1, 0x02];
+    for (time, bpm) in [(1_000_u32, 150_u8), (1_001, 155)] {
+        data.push(0);
+        data.extend(time.to_le_bytes());
+        data.push(bpm);
+    }
+    let mut file = vec![12, 0x10, 0x6C, 0x08];
+    file.extend(u32::try_from(data.len()).unwrap().to_le_bytes());
+    file.extend(b".FIT");
+    file.extend(data);
+    let crc = crate::export::crc(&file);
+    file.extend(crc.to_le_bytes());
+
+    let heart_rate = HeartRate::from_fit(&file).unwrap();
+    // FIT timestamps count from 1989-12-31
+    assert_eq!(
+        heart_rate,
+        HeartRate::from(vec![
+            Sample {
+                time: 631_066_600,
+                bpm: 150
+            },
+            Sample {
+                time: 631_066_601,
+                bpm: 155
+            },
+        ])
+    );
+    assert!(HeartRate::from_fit(b"not a fit file").is_err());
+}
+
+#[test]
+fn heart_rate_align_tests() {
+    use crate::defaults::SEQUENCES;
+
+    let sequence = SEQUENCES
+        .iter()
+        .find(|sequence| sequence.name().starts_with("Boxing"))
+        .unwrap();
+    let session = Session::record(sequence, 70.0, 1_000);
+    // one reading per second, climbing during the first lap then flat
+    let duration = i64::try_from(session.duration().as_secs()).unwrap();
+    let samples = (-10..duration + 10)
+        .map(|offset| Sample {
+            time: 1_000 + offset,
+            bpm: if offset < 30 {
+                100 + u8::try_from(offset.max(0)).unwrap()
+            } else {
+                140
+            },
+        })
+        .collect::<Vec<_>>();
+    let heart_rate = HeartRate::from(samples);
+    assert_eq!(
+        heart_rate.during(&session).len(),
+        usize::try_from(duration).unwrap()
+    );
+
+    let laps = heart_rate.align(&session);
+    assert_eq!(laps.len(), session.laps().len());
+    assert_eq!(laps[0].max, Some(129));
+    assert_eq!(laps[0].average, Some(114));
+    assert_eq!(laps[1].average, Some(140));
+    assert!(laps.iter().any(|lap| lap.rest));
+    assert_eq!(HeartRate::default().align(&session)[0].average, None);
+
+    let mut session = session;
+    assert!(session.set_heart_rate(&heart_rate).is_ok());
+    let elsewhere = HeartRate::from(vec![Sample { time: 0, bpm: 90 }]);
+    assert!(matches!(
+        session.set_heart_rate(&elsewhere),
+        Err(ErrorKind::HeartRateOutsideSession)
+    ));
+    assert_eq!(
+        session.heart_rate().len(),
+        usize::try_from(duration).unwrap()
+    );
+}
+
+#[test]
+fn heart_rate_pause_tests() {
+    use crate::item_list::ItemList;
+    use crate::player::Silent;
+    use crate::sequence::Sequence;
+    use crate::signal::SoundSignal;
+    use crate::sound::Sound;
+    use crate::timer::Timer;
+    use crate::workout::Workout;
+
+    let seconds = std::time::Duration::from_secs;
+    let sequence = Sequence::builder()
+        .name("paused")
+        .sound(Sound::default())
+        .workouts(vec![
+            ItemList::JumpingJack.workout(seconds(5)),
+            Workout::rest(seconds(5)),
+            ItemList::Burpee.workout(seconds(5)),
+        ])
+        .build();
+    let mut timer = Timer::new(
+        seconds(3),
+        &sequence,
+        &SoundSignal::from_muted(true),
+        Box::new(Silent),
+    );
+    timer.toggle();
+    for _ in 0..12 {
+        timer.tick();
+    }
+    // paused for 20 seconds in the middle of the rest
+    let paused = i64::try_from(timer.summary().spent().as_secs()).unwrap();
+    timer.toggle();
+    for _ in 0..20 {
+        timer.tick();
+    }
+    timer.toggle();
+    for _ in 0..20 {
+        timer.tick();
+    }
+    let summary = timer.summary();
+    let now = 100_000;
+    let session = timer.session(70.0, now).unwrap();
+    let started = session.started();
+    assert_eq!(session.ended(), now);
+    assert_eq!(
+        session.ended() - started,
+        i64::try_from(summary.spent().as_secs()).unwrap()
+    );
+    assert_eq!(session.duration(), summary.work + summary.rest);
+
+    // the rest is split around the pause, no lap covers it
+    let intervals = session.intervals();
+    assert_eq!(
+        intervals
+            .iter()
+            .map(|(_, lap)| lap.name())
+            .collect::<Vec<_>>(),
+        ["Jumping Jack", "Rest", "Rest", "Burpee"]
+    );
+    let pause = started + paused..started + paused + 20;
+    assert_eq!(
+        intervals[1].0 + i64::try_from(intervals[1].1.duration().as_secs()).unwrap(),
+        pause.start
+    );
+    assert_eq!(intervals[2].0, pause.end);
+    assert!(intervals[0].0 > started);
+
+    // one reading per second, racing during the pause
+    let heart_rate = HeartRate::from(
+        (started - 10..now + 10)
+            .map(|time| Sample {
+                time,
+                bpm: if pause.contains(&time) { 180 } else { 100 },
+            })
+            .collect::<Vec<_>>(),
+    );
+    let during = heart_rate.during(&session);
+    assert_eq!(during.len(), usize::try_from(now - started).unwrap());
+    let laps = heart_rate.align(&session);
+    assert!(laps.iter().all(|lap| lap.max == Some(100)));
+}
@@ -0,0 +1,1340 @@
+use crate::instructions::Instructions;
+use crate::item::Item;
+use crate::tag::Tag;
+use crate::template::ParameterKind;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use strum_macros::VariantArray;
+use time::Weekday;
+
+#[derive(
+    Display, Default, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, VariantArray,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Locale {
+    #[default]
+    #[display("English")]
+    En,
+    #[display("Français")]
+    Fr,
+}
+
+/// Interface texts, the English translation is the source text
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, VariantArray)]
+pub enum Text {
+    Prepare,
+    StartTimer,
+    PauseTimer,
+    EnableSound,
+    DisableSound,
+    RestartSequence,
+    RestartWorkout,
+    PreviousWorkout,
+    NextWorkout,
+    ShuffleSequence,
+    EmitSignal,
+    TimeLeft,
+    Next,
+    End,
+    Easier,
+    Harder,
+    SeeExercises,
+    Home,
+    Total,
+    Original,
+    Minutes,
+    Equipment,
+    BodyWeight,
+    History,
+    Language,
+    Programs,
+    StartProgram,
+    Today,
+    RestDay,
+    Week,
+    Done,
+    Finished,
+    Work,
+    Rest,
+    Skipped,
+    Pauses,
+    TimeSpent,
+    Effort,
+    NextSequence,
+    Setup,
+    Execution,
+    CommonMistakes,
+    Breathing,
+    Remove,
+    Round,
+    Exercise,
+    Duration,
+    Notes,
+    Difficulty,
+    Rounds,
+    StartTimerFor,
+    HeartRate,
+    AverageBpm,
+    Max,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+    ShareSeed,
+    Seed,
+    EasierVariants,
+    HarderVariants,
+    SeeExercisesTitle,
+    GoHome,
+    EstimatedEffort,
+    Load,
+    ScaleDuration,
+    Tags,
+    Items,
+    LastWeek,
+    AllTime,
+    Sessions,
+    UnknownItem,
+    UnknownSequence,
+    EmptySequence,
+    BroadcastToRoom,
+    BroadcastTitle,
+    Sync,
+    Relay,
+    Room,
+    Follow,
+    FollowTitle,
+    FollowingRoom,
+    DisconnectedFromRoom,
+    StartPause,
+    MuteUnmute,
+    Ring,
+    ShowHideShortcuts,
+    KeyboardShortcuts,
+    PressKey,
+    Change,
+    Close,
+    GymDisplay,
+    DoubleTapToLeave,
+}
+
+impl Locale {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Fr => "fr",
+        }
+    }
+    /// Locale from a language tag like `fr-FR`, English when unsupported
+    pub fn from_language(language: &str) -> Self {
+        if language.to_lowercase().starts_with("fr") {
+            Self::Fr
+        } else {
+            Self::En
+        }
+    }
+    fn catalog(&self) -> Option<&'static Catalog> {
+        match self {
+            Self::En => None,
+            Self::Fr => Some(&FRENCH),
+        }
+    }
+    pub fn text(&self, text: Text) -> &'static str {
+        self.catalog()
+            .and_then(|catalog| lookup(catalog.texts, text))
+            .unwrap_or_else(|| text.english())
+    }
+    /// Template parameter label
+    pub fn parameter(&self, kind: ParameterKind) -> &'static str {
+        self.text(match kind {
+            ParameterKind::Rounds => Text::Rounds,
+            ParameterKind::Work => Text::Work,
+            ParameterKind::Rest => Text::Rest,
+        })
+    }
+    pub fn weekday(&self, weekday: Weekday) -> &'static str {
+        self.text(match weekday {
+            Weekday::Monday => Text::Monday,
+            Weekday::Tuesday => Text::Tuesday,
+            Weekday::Wednesday => Text::Wednesday,
+            Weekday::Thursday => Text::Thursday,
+            Weekday::Friday => Text::Friday,
+            Weekday::Saturday => Text::Saturday,
+            Weekday::Sunday => Text::Sunday,
+        })
+    }
+    /// Item name, items outside the catalog keep their name
+    pub fn item_name(&self, item: &Item) -> String {
+        self.catalog()
+            .and_then(|catalog| lookup(catalog.items, item.slug().as_str()))
+            .map_or_else(|| item.name().to_string(), str::to_string)
+    }
+    pub fn item_description(&self, item: &Item) -> Option<String> {
+        let description = item.description().as_ref()?;
+        self.catalog()
+            .and_then(|catalog| lookup(catalog.descriptions, item.slug().as_str()))
+            .map(str::to_string)
+            .or_else(|| Some(description.clone()))
+    }
+    /// Item with its icon, like the `Item` display
+    pub fn item(&self, item: &Item) -> String {
+        format!("{}{}", item.icon().unwrap_or('‎'), self.item_name(item))
+    }
+    /// Item instructions, untranslated ones stay in English
+    pub fn item_instructions(&self, item: &Item) -> Option<Instructions> {
+        let instructions = item.instructions()?;
+        let Some(guide) = self.catalog().and_then(|catalog| {
+            catalog
+                .instructions
+                .iter()
+                .find(|(slug, _)| *slug == item.slug())
+                .map(|(_, guide)| guide)
+        }) else {
+            return Some(instructions.clone());
+        };
+        let text = |text: &'static str| (!text.is_empty()).then_some(text);
+        Some(
+            Instructions::builder()
+                .maybe_setup(text(guide.setup))
+                .steps(guide.steps)
+                .mistakes(guide.mistakes)
+                .maybe_breathing(text(guide.breathing))
+                .build(),
+        )
+    }
+    pub fn tag(&self, tag: &Tag) -> String {
+        self.catalog()
+            .and_then(|catalog| lookup(catalog.tags, tag.slug().as_str()))
+            .map_or_else(|| tag.to_string(), str::to_string)
+    }
+}
+
+impl Text {
+    fn english(self) -> &'static str {
+        match self {
+            Self::Prepare => crate::defaults::PREPARE_LABEL,
+            Self::StartTimer => "Start timer",
+            Self::PauseTimer => "Pause timer",
+            Self::EnableSound => "Enable sound",
+            Self::DisableSound => "Disable sound",
+            Self::RestartSequence => "Restart sequence",
+            Self::RestartWorkout => "Restart workout",
+            Self::PreviousWorkout => "Previous workout",
+            Self::NextWorkout => "Next workout",
+            Self::ShuffleSequence => "Shuffle sequence",
+            Self::EmitSignal => "Emit signal sound",
+            Self::TimeLeft => "Time left",
+            Self::Next => "Next",
+            Self::End => "END",
+            Self::Easier => "Easier",
+            Self::Harder => "Harder",
+            Self::SeeExercises => "See exercises",
+            Self::Home => "Home",
+            Self::Total => "Total",
+            Self::Original => "original",
+            Self::Minutes => "minutes",
+            Self::Equipment => "Equipment",
+            Self::BodyWeight => "Body weight (kg)",
+            Self::History => "History",
+            Self::Language => "Language",
+            Self::Programs => "Programs",
+            Self::StartProgram => "Start the program",
+            Self::Today => "Today's session",
+            Self::RestDay => "Rest day",
+            Self::Week => "Week",
+            Self::Done => "Done",
+            Self::Finished => "Well done!",
+            Self::Work => "Work",
+            Self::Rest => "Rest",
+            Self::Skipped => "Skipped",
+            Self::Pauses => "Pauses",
+            Self::TimeSpent => "Time spent",
+            Self::Effort => "Perceived effort (RPE)",
+            Self::NextSequence => "Next sequence",
+            Self::Setup => "Setup",
+            Self::Execution => "Execution",
+            Self::CommonMistakes => "Common mistakes",
+            Self::Breathing => "Breathing",
+            Self::Remove => "Remove",
+            Self::Round => "Round",
+            Self::Exercise => "Exercise",
+            Self::Duration => "Duration",
+            Self::Notes => "Notes",
+            Self::Difficulty => "Difficulty",
+            Self::Rounds => "Rounds",
+            Self::StartTimerFor => "Start timer for",
+            Self::HeartRate => "HR",
+            Self::AverageBpm => "bpm avg",
+            Self::Max => "max",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Sunday => "Sunday",
+            Self::ShareSeed => "Share this shuffled sequence",
+            Self::Seed => "Seed",
+            Self::EasierVariants => "Swap exercises for easier variants",
+            Self::HarderVariants => "Swap exercises for harder variants",
+            Self::SeeExercisesTitle => "See exercises in this sequence",
+            Self::GoHome => "Go to sequence list",
+            Self::EstimatedEffort => "Estimated effort for your body weight",
+            Self::Load => "load",
+            Self::ScaleDuration => "Scale the sequence to a total duration",
+            Self::Tags => "Tags",
+            Self::Items => "Items",
+            Self::LastWeek => "Last 7 days",
+            Self::AllTime => "All time",
+            Self::Sessions => "sessions",
+            Self::UnknownItem => "unknown item",
+            Self::UnknownSequence => "unknown sequence",
+            Self::EmptySequence => "empty sequence",
+            Self::BroadcastToRoom => "Broadcast to room",
+            Self::BroadcastTitle => "Devices following this room mirror the timer",
+            Self::Sync => "Sync",
+            Self::Relay => "Relay",
+            Self::Room => "Room",
+            Self::Follow => "Follow",
+            Self::FollowTitle => "Mirror the timer broadcast to this room",
+            Self::FollowingRoom => "Following room",
+            Self::DisconnectedFromRoom => "Disconnected from room",
+            Self::StartPause => "Start / pause",
+            Self::MuteUnmute => "Mute / unmute",
+            Self::Ring => "Ring",
+            Self::ShowHideShortcuts => "Show / hide shortcuts",
+            Self::KeyboardShortcuts => "Keyboard shortcuts",
+            Self::PressKey => "Press a key to bind it to this action",
+            Self::Change => "Change",
+            Self::Close => "Close",
+            Self::GymDisplay => "Fullscreen display for the gym",
+            Self::DoubleTapToLeave => "Double tap to leave",
+        }
+    }
+}
+
+struct Catalog {
+    texts: &'static [(Text, &'static str)],
+    /// Keyed by item slug
+    items: &'static [(&'static str, &'static str)],
+    /// Keyed by item slug
+    descriptions: &'static [(&'static str, &'static str)],
+    /// Keyed by tag slug
+    tags: &'static [(&'static str, &'static str)],
+    /// Keyed by item slug
+    instructions: &'static [(&'static str, Guide)],
+}
+
+/// Translated `Instructions`, in the same order
+struct Guide {
+    setup: &'static str,
+    steps: &'static [&'static str],
+    mistakes: &'static [&'static str],
+    breathing: &'static str,
+}
+
+fn lookup<K: PartialEq<Q>, Q>(
+    entries: &'static [(K, &'static str)],
+    key: Q,
+) -> Option<&'static str> {
+    entries
+        .iter()
+        .find(|(entry, _)| *entry == key)
+        .map(|(_, value)| *value)
+}
+
+static FRENCH: Catalog = Catalog {
+    texts: &[
+        (Text::Prepare, "Préparation"),
+        (Text::StartTimer, "Démarrer le minuteur"),
+        (Text::PauseTimer, "Mettre en pause"),
+        (Text::EnableSound, "Activer le son"),
+        (Text::DisableSound, "Couper le son"),
+        (Text::RestartSequence, "Recommencer la séance"),
+        (Text::RestartWorkout, "Recommencer l'exercice"),
+        (Text::PreviousWorkout, "Exercice précédent"),
+        (Text::NextWorkout, "Exercice suivant"),
+        (Text::ShuffleSequence, "Mélanger la séance"),
+        (Text::EmitSignal, "Émettre le signal sonore"),
+        (Text::TimeLeft, "Temps restant"),
+        (Text::Next, "Ensuite"),
+        (Text::End, "FIN"),
+        (Text::Easier, "Plus facile"),
+        (Text::Harder, "Plus difficile"),
+        (Text::SeeExercises, "Voir les exercices"),
+        (Text::Home, "Accueil"),
+        (Text::Total, "Total"),
+        (Text::Original, "d'origine"),
+        (Text::Minutes, "minutes"),
+        (Text::Equipment, "Matériel"),
+        (Text::BodyWeight, "Poids (kg)"),
+        (Text::History, "Historique"),
+        (Text::Language, "Langue"),
+        (Text::Programs, "Programmes"),
+        (Text::StartProgram, "Commencer le programme"),
+        (Text::Today, "Séance du jour"),
+        (Text::RestDay, "Jour de repos"),
+        (Text::Week, "Semaine"),
+        (Text::Done, "Fait"),
+        (Text::Finished, "Bravo !"),
+        (Text::Work, "Travail"),
+        (Text::Rest, "Repos"),
+        (Text::Skipped, "Sautés"),
+        (Text::Pauses, "Pauses"),
+        (Text::TimeSpent, "Temps passé"),
+        (Text::Effort, "Effort perçu (RPE)"),
+        (Text::NextSequence, "Séance suivante"),
+        (Text::Setup, "Position de départ"),
+        (Text::Execution, "Exécution"),
+        (Text::CommonMistakes, "Erreurs fréquentes"),
+        (Text::Breathing, "Respiration"),
+        (Text::Remove, "Supprimer"),
+        (Text::Round, "Round"),
+        (Text::Exercise, "Exercice"),
+        (Text::Duration, "Durée"),
+        (Text::Notes, "Notes"),
+        (Text::Difficulty, "Difficulté"),
+        (Text::Rounds, "Rounds"),
+        (Text::StartTimerFor, "Démarrer le minuteur pour"),
+        (Text::HeartRate, "FC"),
+        (Text::AverageBpm, "bpm moy."),
+        (Text::Max, "max"),
+        (Text::Monday, "Lundi"),
+        (Text::Tuesday, "Mardi"),
+        (Text::Wednesday, "Mercredi"),
+        (Text::Thursday, "Jeudi"),
+        (Text::Friday, "Vendredi"),
+        (Text::Saturday, "Samedi"),
+        (Text::Sunday, "Dimanche"),
+        (Text::ShareSeed, "Partager cette séance mélangée"),
+        (Text::Seed, "Graine"),
+        (
+            Text::EasierVariants,
+            "Remplacer par des variantes plus faciles",
+        ),
+        (
+            Text::HarderVariants,
+            "Remplacer par des variantes plus difficiles",
+        ),
+        (
+            Text::SeeExercisesTitle,
+            "Voir les exercices de cette séance",
+        ),
+        (Text::GoHome, "Aller à la liste des séances"),
+        (Text::EstimatedEffort, "Effort estimé pour votre poids"),
+        (Text::Load, "charge"),
+        (Text::ScaleDuration, "Adapter la séance à une durée totale"),
+        (Text::Tags, "Tags"),
+        (Text::Items, "Exercices"),
+        (Text::LastWeek, "7 derniers jours"),
+        (Text::AllTime, "Depuis le début"),
+        (Text::Sessions, "séances"),
+        (Text::UnknownItem, "exercice inconnu"),
+        (Text::UnknownSequence, "séance inconnue"),
+        (Text::EmptySequence, "séance vide"),
+        (Text::BroadcastToRoom, "Diffuser dans la salle"),
+        (
+            Text::BroadcastTitle,
+            "Les appareils qui suivent cette salle reproduisent le minuteur",
+        ),
+        (Text::Sync, "Synchronisation"),
+        (Text::Relay, "Relais"),
+        (Text::Room, "Salle"),
+        (Text::Follow, "Suivre"),
+        (
+            Text::FollowTitle,
+            "Reproduire le minuteur diffusé dans cette salle",
+        ),
+        (Text::FollowingRoom, "Suivi de la salle"),
+        (Text::DisconnectedFromRoom, "Déconnecté de la salle"),
+        (Text::StartPause, "Démarrer / pause"),
+        (Text::MuteUnmute, "Couper / rétablir le son"),
+        (Text::Ring, "Sonner"),
+        (Text::ShowHideShortcuts, "Afficher / masquer les raccourcis"),
+        (Text::KeyboardShortcuts, "Raccourcis clavier"),
+        (
+            Text::PressKey,
+            "Appuyer sur une touche pour l’associer à cette action",
+        ),
+        (Text::Change, "Modifier"),
+        (Text::Close, "Fermer"),
+        (Text::GymDisplay, "Affichage plein écran pour la salle"),
+        (Text::DoubleTapToLeave, "Toucher deux fois pour quitter"),
+    ],
+    items: &[
+        ("alternate-step", "Montées de marche alternées"),
+        ("arms-rotation", "Rotation des bras"),
+        ("box-jump", "Saut sur box"),
+        ("boxing-round", "Round de boxe"),
+        ("burpee", "Burpee"),
+        ("burpee-navy-seal", "Burpee Navy Seal"),
+        ("burpee-push-up", "Burpee avec pompe"),
+        ("butt-kicks", "Talons-fesses"),
+        ("commando-plank", "Planche commando"),
+        ("crunches", "Crunchs"),
+        ("elbows-rotation", "Rotation des coudes"),
+        ("feet-rotation", "Rotation des chevilles"),
+        ("fire-warm-up", "Échauffement"),
+        ("head-rotation", "Rotation de la tête"),
+        ("heels-raises", "Montées sur pointes"),
+        ("high-knees", "Montées de genoux"),
+        ("hip-thrust", "Hip thrust"),
+        ("hips-rotation", "Rotation des hanches"),
+        ("inchworm", "Chenille"),
+        ("jump-rope", "Corde à sauter"),
+        ("jump-rope-criss-cross", "Corde à sauter croisée"),
+        ("jump-rope-double-unders", "Corde à sauter double"),
+        ("jump-rope-high-knees", "Corde à sauter genoux hauts"),
+        ("jumping-jack", "Jumping jack"),
+        ("jumps", "Sauts"),
+        ("jumps-forward", "Sauts en avant"),
+        ("knees-rotation", "Rotation des genoux"),
+        ("leg-swings-front", "Balancés de jambe avant"),
+        ("leg-swings-side", "Balancés de jambe latéraux"),
+        ("lunge", "Fente"),
+        ("lunge-jump", "Fente sautée"),
+        ("lunge-reverse", "Fente arrière"),
+        ("lunge-walking", "Fente marchée"),
+        ("mountain-climber", "Mountain climber"),
+        ("plank", "Planche"),
+        ("plank-shoulder-tap", "Planche touche épaule"),
+        ("pull-up", "Traction"),
+        ("push-up", "Pompe"),
+        ("rest", "Repos"),
+        ("run", "Course"),
+        ("scissor-kick", "Battements de jambes"),
+        ("shoulder-rotation", "Rotation des épaules"),
+        ("side-plank", "Planche latérale"),
+        ("side-steps", "Pas chassés"),
+        ("single-leg-touch-toes", "Touche pointe sur une jambe"),
+        ("skating-step", "Pas de patineur"),
+        ("speed-step", "Pas rapides"),
+        ("sprint", "Sprint"),
+        ("squat", "Squat"),
+        ("squat-bulgarian", "Squat bulgare"),
+        ("squat-jump", "Squat sauté"),
+        ("squat-single-leg", "Squat sur une jambe"),
+        ("squat-sumo", "Squat sumo"),
+        ("walk", "Marche"),
+        ("windmill", "Moulin à vent"),
+        ("workout", "Exercice"),
+        ("wrists-rotation", "Rotation des poignets"),
+    ],
+    descriptions: &[
+        ("fire-warm-up", "Échauffement générique"),
+        ("workout", "Exercice générique"),
+    ],
+    tags: &[
+        ("rest", "Repos"),
+        ("drink", "Boire"),
+        ("hiit", "HiiT"),
+        ("warmup", "Échauffement"),
+        ("round", "Round"),
+        ("punch", "Coup de poing"),
+        ("roll", "Rotation du buste"),
+        ("slip", "Esquive"),
+        ("pull", "Retrait"),
+        ("full", "Corps entier"),
+        ("core", "Gainage"),
+        ("abs", "Abdominaux"),
+        ("pectorals", "Pectoraux"),
+        ("obliques", "Obliques"),
+        ("triceps", "Triceps"),
+        ("legs", "Jambes"),
+        ("hip", "Hanches"),
+        ("shoulder", "Épaules"),
+        ("buttocks", "Fessiers"),
+        ("coordination", "Coordination"),
+        ("balance", "Équilibre"),
+        ("rotation", "Rotation"),
+        ("stamina", "Endurance"),
+        ("strength", "Force"),
+        ("stationary", "Statique"),
+        ("stretching", "Étirements"),
+        ("dynamic", "Dynamique"),
+        ("footwork", "Jeu de jambes"),
+        ("none", "Aucun"),
+        ("dumbbell", "Haltère"),
+        ("kettlebell", "Kettlebell"),
+        ("medicineball", "Médecine-ball"),
+        ("resistanceband", "Élastique"),
+        ("jumprope", "Corde à sauter"),
+        ("mat", "Tapis"),
+        ("bench", "Banc"),
+        ("box", "Box"),
+        ("wall", "Mur"),
+        ("chair", "Chaise"),
+        ("pullupbar", "Barre de traction"),
+        ("rings", "Anneaux"),
+        ("swissball", "Swiss ball"),
+        ("abswheel", "Roue abdominale"),
+        ("plank", "Planche"),
+        ("side", "Latéral"),
+        ("commando", "Commando"),
+        ("jack", "Jack"),
+        ("shouldertap", "Touche épaule"),
+        ("spiderman", "Spiderman"),
+        ("squat", "Squat"),
+        ("jump", "Sauté"),
+        ("sumo", "Sumo"),
+        ("singleleg", "Une jambe"),
+        ("bulgarian", "Bulgare"),
+    ],
+    instructions: &[
+        (
+            "plank",
+            Guide {
+                setup: "Avant-bras au sol, coudes sous les épaules",
+                steps: &[
+                    "Lever le bassin pour aligner épaules, hanches et talons",
+                    "Serrer les fessiers et les abdominaux, tenir la position",
+                ],
+                mistakes: &[
+                    "Bassin trop haut",
+                    "Bas du dos creusé",
+                    "Bloquer la respiration",
+                ],
+                breathing: "Respirer lentement et régulièrement",
+            },
+        ),
+        (
+            "jumping-jack",
+            Guide {
+                setup: "Debout, pieds joints, bras le long du corps",
+                steps: &[
+                    "Sauter en écartant les pieds et en levant les bras au-dessus de la tête",
+                    "Revenir en sautant à la position de départ",
+                ],
+                mistakes: &["Atterrir sur les talons", "Genoux raides"],
+                breathing: "Garder un rythme régulier, expirer tous les deux sauts",
+            },
+        ),
+        (
+            "mountain-climber",
+            Guide {
+                setup: "Planche haute, mains sous les épaules",
+                steps: &[
+                    "Ramener un genou vers la poitrine",
+                    "Alterner rapidement les jambes",
+                ],
+                mistakes: &["Bassin qui rebondit", "Mains devant les épaules"],
+                breathing: "Respirations courtes et régulières",
+            },
+        ),
+        (
+            "squat",
+            Guide {
+                setup: "Pieds écartés largeur d'épaules, pointes légèrement vers l'extérieur",
+                steps: &[
+                    "Pousser les hanches en arrière et plier les genoux",
+                    "Descendre jusqu'à ce que les cuisses soient parallèles au sol",
+                    "Pousser sur les talons pour remonter",
+                ],
+                mistakes: &["Genoux qui rentrent", "Talons qui décollent", "Dos rond"],
+                breathing: "Inspirer en descendant, expirer en remontant",
+            },
+        ),
+        (
+            "push-up",
+            Guide {
+                setup: "Mains un peu plus larges que les épaules, corps aligné de la tête aux talons",
+                steps: &[
+                    "Descendre la poitrine près du sol, coudes à 45 degrés",
+                    "Repousser le sol jusqu'à tendre les bras",
+                ],
+                mistakes: &[
+                    "Bassin qui s'affaisse",
+                    "Coudes écartés",
+                    "Amplitude incomplète",
+                ],
+                breathing: "Inspirer en descendant, expirer en poussant",
+            },
+        ),
+        (
+            "lunge",
+            Guide {
+                setup: "Debout, pieds écartés largeur de hanches",
+                steps: &[
+                    "Faire un pas en avant et descendre le genou arrière vers le sol",
+                    "Garder le genou avant au-dessus de la cheville",
+                    "Pousser sur le talon avant pour revenir",
+                ],
+                mistakes: &["Genou avant au-delà des orteils", "Buste penché en avant"],
+                breathing: "Inspirer en descendant, expirer en remontant",
+            },
+        ),
+        (
+            "burpee",
+            Guide {
+                setup: "Debout, pieds écartés largeur d'épaules",
+                steps: &[
+                    "S'accroupir et poser les mains au sol",
+                    "Sauter les pieds en arrière en planche",
+                    "Ramener les pieds vers les mains en sautant",
+                    "Sauter en levant les bras au-dessus de la tête",
+                ],
+                mistakes: &[
+                    "Bassin qui s'affaisse en planche",
+                    "Atterrir jambes tendues",
+                ],
+                breathing: "Expirer pendant le saut",
+            },
+        ),
+        (
+            "crunches",
+            Guide {
+                setup: "Allongé sur le dos, genoux pliés, pieds à plat",
+                steps: &[
+                    "Enrouler les épaules au-dessus du sol avec les abdominaux",
+                    "Redescendre en contrôlant",
+                ],
+                mistakes: &["Tirer sur la nuque", "Prendre de l'élan"],
+                breathing: "Expirer en montant",
+            },
+        ),
+        (
+            "boxing-round",
+            Guide {
+                setup: "Garde haute, menton rentré, pied avant devant",
+                steps: &[
+                    "Se déplacer sur l'avant des pieds",
+                    "Enchaîner les combinaisons et revenir en garde",
+                    "Alterner défense et jeu de jambes",
+                ],
+                mistakes: &["Baisser les mains", "Croiser les pieds", "Rester immobile"],
+                breathing: "Expirer fort à chaque coup",
+            },
+        ),
+        (
+            "jump-rope",
+            Guide {
+                setup: "Coudes près du corps, corde derrière les talons",
+                steps: &[
+                    "Faire tourner la corde avec les poignets",
+                    "Sauter juste assez haut pour laisser passer la corde",
+                ],
+                mistakes: &["Tourner avec les bras", "Sauter trop haut"],
+                breathing: "Respirer par le nez à un rythme régulier",
+            },
+        ),
+        (
+            "walk",
+            Guide {
+                setup: "Debout, épaules relâchées",
+                steps: &[
+                    "Dérouler le pied du talon aux orteils",
+                    "Balancer les bras naturellement",
+                ],
+                mistakes: &["Regarder ses pieds"],
+                breathing: "Respirer naturellement",
+            },
+        ),
+        (
+            "run",
+            Guide {
+                setup: "Debout, buste légèrement penché en avant",
+                steps: &[
+                    "Poser le pied sous les hanches, sur le milieu du pied",
+                    "Balancer les bras d'avant en arrière, sans croiser",
+                ],
+                mistakes: &["Foulées trop longues", "Épaules crispées"],
+                breathing: "Respirer au rythme des foulées",
+            },
+        ),
+        (
+            "sprint",
+            Guide {
+                setup: "Position athlétique, poids sur le pied avant",
+                steps: &[
+                    "Monter les genoux et pousser le sol vers l'arrière",
+                    "Balancer fort les bras depuis les épaules",
+                ],
+                mistakes: &["Se pencher en arrière", "Atterrir sur les talons"],
+                breathing: "Respirations courtes et puissantes",
+            },
+        ),
+        (
+            "side-steps",
+            Guide {
+                setup: "Genoux légèrement fléchis, garde haute",
+                steps: &[
+                    "Faire un pas de côté avec le pied qui mène",
+                    "Ramener l'autre pied à largeur d'épaules",
+                ],
+                mistakes: &["Croiser les pieds", "Se redresser"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "plank-shoulder-tap",
+            Guide {
+                setup: "Planche haute, pieds écartés largeur de hanches",
+                steps: &[
+                    "Toucher l'épaule opposée avec une main",
+                    "La reposer et changer de main",
+                ],
+                mistakes: &["Hanches qui basculent"],
+                breathing: "Expirer à chaque touche",
+            },
+        ),
+        (
+            "side-plank",
+            Guide {
+                setup: "Sur le côté, coude sous l'épaule, jambes superposées",
+                steps: &[
+                    "Lever le bassin jusqu'à aligner le corps",
+                    "Tenir puis changer de côté",
+                ],
+                mistakes: &["Bassin qui tombe", "Épaule remontée vers l'oreille"],
+                breathing: "Respirer lentement et régulièrement",
+            },
+        ),
+        (
+            "commando-plank",
+            Guide {
+                setup: "Planche sur les avant-bras, pieds écartés largeur de hanches",
+                steps: &[
+                    "Monter sur une main puis sur l'autre",
+                    "Redescendre un avant-bras après l'autre",
+                ],
+                mistakes: &["Bassin qui se balance", "Toujours commencer du même bras"],
+                breathing: "Expirer en montant",
+            },
+        ),
+        (
+            "head-rotation",
+            Guide {
+                setup: "Debout, épaules basses",
+                steps: &[
+                    "Faire lentement rouler la tête en cercle",
+                    "Changer de sens",
+                ],
+                mistakes: &["Aller trop vite"],
+                breathing: "Respirer lentement",
+            },
+        ),
+        (
+            "shoulder-rotation",
+            Guide {
+                setup: "Debout, bras relâchés",
+                steps: &[
+                    "Faire de grands cercles des épaules vers l'arrière",
+                    "Puis vers l'avant",
+                ],
+                mistakes: &["Hausser les épaules sans rouler"],
+                breathing: "Respirer lentement",
+            },
+        ),
+        (
+            "arms-rotation",
+            Guide {
+                setup: "Debout, pieds écartés, bras tendus sur les côtés",
+                steps: &[
+                    "Dessiner des cercles bras tendus",
+                    "Agrandir les cercles puis changer de sens",
+                ],
+                mistakes: &["Plier les coudes"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "elbows-rotation",
+            Guide {
+                setup: "Bras devant soi, haut des bras immobile",
+                steps: &[
+                    "Faire tourner les avant-bras autour des coudes",
+                    "Changer de sens",
+                ],
+                mistakes: &["Bouger les épaules"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "wrists-rotation",
+            Guide {
+                setup: "Doigts croisés devant la poitrine",
+                steps: &["Faire tourner les poignets", "Changer de sens"],
+                mistakes: &["Forcer l'amplitude"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "hips-rotation",
+            Guide {
+                setup: "Mains sur les hanches, pieds écartés largeur d'épaules",
+                steps: &[
+                    "Dessiner de grands cercles avec le bassin",
+                    "Changer de sens",
+                ],
+                mistakes: &["Bouger les épaules au lieu du bassin"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "knees-rotation",
+            Guide {
+                setup: "Pieds joints, mains sur les genoux",
+                steps: &["Faire tourner les genoux ensemble", "Changer de sens"],
+                mistakes: &["Verrouiller les genoux"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "feet-rotation",
+            Guide {
+                setup: "Sur un pied, pointe de l'autre au sol",
+                steps: &["Faire tourner la cheville", "Changer de sens puis de pied"],
+                mistakes: &["Précipiter les cercles"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "heels-raises",
+            Guide {
+                setup: "Debout, pieds écartés largeur de hanches",
+                steps: &[
+                    "Monter sur la pointe des pieds",
+                    "Redescendre lentement les talons",
+                ],
+                mistakes: &["Basculer sur l'extérieur des pieds"],
+                breathing: "Expirer en montant",
+            },
+        ),
+        (
+            "inchworm",
+            Guide {
+                setup: "Debout, pieds écartés largeur de hanches",
+                steps: &[
+                    "Se pencher en avant et poser les mains au sol",
+                    "Avancer les mains jusqu'à la planche",
+                    "Revenir avec les mains et se relever",
+                ],
+                mistakes: &["Trop plier les genoux", "Bassin qui s'affaisse en planche"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "leg-swings-front",
+            Guide {
+                setup: "Sur une jambe, une main au mur si besoin",
+                steps: &[
+                    "Balancer la jambe libre d'avant en arrière",
+                    "Changer de jambe",
+                ],
+                mistakes: &["Arrondir le dos"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "leg-swings-side",
+            Guide {
+                setup: "Sur une jambe, face à un mur",
+                steps: &[
+                    "Balancer la jambe libre de gauche à droite",
+                    "Changer de jambe",
+                ],
+                mistakes: &["Tourner le bassin"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "single-leg-touch-toes",
+            Guide {
+                setup: "Sur une jambe, l'autre légèrement en arrière",
+                steps: &[
+                    "Basculer en avant et aller toucher les orteils",
+                    "Se redresser puis changer de jambe",
+                ],
+                mistakes: &["Arrondir le dos", "Verrouiller le genou d'appui"],
+                breathing: "Expirer en descendant",
+            },
+        ),
+        (
+            "windmill",
+            Guide {
+                setup: "Pieds très écartés, bras tendus sur les côtés",
+                steps: &[
+                    "Se pencher et toucher le pied opposé",
+                    "Se relever et changer de côté",
+                ],
+                mistakes: &["Plier les bras"],
+                breathing: "Expirer en descendant",
+            },
+        ),
+        (
+            "butt-kicks",
+            Guide {
+                setup: "Debout, mains derrière les fessiers",
+                steps: &[
+                    "Courir sur place, talons vers les fessiers",
+                    "Rester léger sur l'avant des pieds",
+                ],
+                mistakes: &["Se pencher en avant"],
+                breathing: "Respirer en rythme",
+            },
+        ),
+        (
+            "high-knees",
+            Guide {
+                setup: "Debout, bras fléchis",
+                steps: &[
+                    "Courir sur place en montant les genoux à hauteur de hanches",
+                    "Balancer les bras",
+                ],
+                mistakes: &["Se pencher en arrière", "Genoux trop bas"],
+                breathing: "Respirer en rythme",
+            },
+        ),
+        (
+            "squat-bulgarian",
+            Guide {
+                setup: "Pied arrière sur un banc, pied avant devant",
+                steps: &[
+                    "Descendre le genou arrière vers le sol",
+                    "Pousser sur le talon avant pour remonter",
+                ],
+                mistakes: &["Genou avant qui rentre", "Pied avant trop près du banc"],
+                breathing: "Inspirer en descendant, expirer en remontant",
+            },
+        ),
+        (
+            "squat-single-leg",
+            Guide {
+                setup: "Sur une jambe, l'autre tendue devant",
+                steps: &[
+                    "Descendre le bassin en arrière sur une jambe",
+                    "Remonter sans poser l'autre pied",
+                ],
+                mistakes: &["Genou qui rentre", "Perdre l'équilibre en arrière"],
+                breathing: "Inspirer en descendant, expirer en remontant",
+            },
+        ),
+        (
+            "squat-jump",
+            Guide {
+                setup: "Pieds écartés largeur d'épaules",
+                steps: &[
+                    "Descendre en squat",
+                    "Sauter de façon explosive",
+                    "Atterrir en douceur dans le squat",
+                ],
+                mistakes: &["Atterrir jambes tendues", "Genoux qui rentrent"],
+                breathing: "Expirer pendant le saut",
+            },
+        ),
+        (
+            "squat-sumo",
+            Guide {
+                setup: "Pieds très écartés, pointes vers l'extérieur",
+                steps: &[
+                    "Descendre le bassin entre les talons",
+                    "Pousser les genoux vers l'extérieur et remonter",
+                ],
+                mistakes: &["Genoux qui rentrent", "Se pencher en avant"],
+                breathing: "Inspirer en descendant, expirer en remontant",
+            },
+        ),
+        (
+            "pull-up",
+            Guide {
+                setup: "Suspendu à la barre, mains écartées largeur d'épaules",
+                steps: &[
+                    "Tirer la poitrine vers la barre",
+                    "Redescendre en contrôle bras tendus",
+                ],
+                mistakes: &["Balancer les jambes", "Amplitude réduite"],
+                breathing: "Expirer en tirant",
+            },
+        ),
+        (
+            "speed-step",
+            Guide {
+                setup: "Genoux légèrement fléchis, sur l'avant des pieds",
+                steps: &["Piétiner le plus vite possible", "Garder de petits pas"],
+                mistakes: &["Rester sur les talons"],
+                breathing: "Respirations courtes et régulières",
+            },
+        ),
+        (
+            "skating-step",
+            Guide {
+                setup: "Sur une jambe, genou fléchi",
+                steps: &[
+                    "Bondir de côté sur l'autre jambe",
+                    "Croiser la jambe libre derrière et repartir",
+                ],
+                mistakes: &["Atterrir genou tendu"],
+                breathing: "Expirer à chaque bond",
+            },
+        ),
+        (
+            "alternate-step",
+            Guide {
+                setup: "Face à une marche ou une caisse basse",
+                steps: &[
+                    "Monter un pied puis l'autre",
+                    "Redescendre et changer de pied",
+                ],
+                mistakes: &["Pousser sur le pied arrière"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "lunge-reverse",
+            Guide {
+                setup: "Debout, pieds écartés largeur de hanches",
+                steps: &[
+                    "Reculer d'un pas et descendre le genou arrière vers le sol",
+                    "Pousser sur le talon avant pour revenir",
+                ],
+                mistakes: &["Se pencher en avant", "Genou avant qui rentre"],
+                breathing: "Inspirer en descendant, expirer en remontant",
+            },
+        ),
+        (
+            "lunge-jump",
+            Guide {
+                setup: "Position de fente, genou arrière décollé",
+                steps: &[
+                    "Sauter et changer de jambe en l'air",
+                    "Atterrir en douceur en fente",
+                ],
+                mistakes: &["Atterrir jambes tendues", "Genou avant au-delà des orteils"],
+                breathing: "Expirer pendant le saut",
+            },
+        ),
+        (
+            "lunge-walking",
+            Guide {
+                setup: "Debout, pieds écartés largeur de hanches",
+                steps: &[
+                    "Faire une fente avant",
+                    "Ramener le pied arrière devant pour la fente suivante",
+                ],
+                mistakes: &["Pas trop courts", "Se pencher en avant"],
+                breathing: "Inspirer en descendant, expirer en remontant",
+            },
+        ),
+        (
+            "burpee-push-up",
+            Guide {
+                setup: "Debout, pieds écartés largeur d'épaules",
+                steps: &[
+                    "S'accroupir et sauter les pieds en arrière en planche",
+                    "Faire une pompe",
+                    "Ramener les pieds vers les mains et sauter",
+                ],
+                mistakes: &["Bassin qui s'affaisse pendant la pompe", "Sauter la pompe"],
+                breathing: "Expirer en poussant et pendant le saut",
+            },
+        ),
+        (
+            "burpee-navy-seal",
+            Guide {
+                setup: "Debout, pieds écartés largeur d'épaules",
+                steps: &[
+                    "Sauter en planche et faire une pompe",
+                    "Amener chaque genou vers le coude opposé, avec une pompe après chacun",
+                    "Ramener les pieds vers les mains et sauter",
+                ],
+                mistakes: &["Bâcler les montées de genou", "Bassin qui s'affaisse"],
+                breathing: "Expirer à chaque effort",
+            },
+        ),
+        (
+            "jumps",
+            Guide {
+                setup: "Pieds écartés largeur de hanches, genoux souples",
+                steps: &[
+                    "Lancer les bras et sauter verticalement",
+                    "Atterrir en douceur sur l'avant des pieds",
+                ],
+                mistakes: &["Atterrir jambes tendues"],
+                breathing: "Expirer pendant le saut",
+            },
+        ),
+        (
+            "jumps-forward",
+            Guide {
+                setup: "Pieds écartés largeur de hanches, genoux fléchis",
+                steps: &[
+                    "Lancer les bras et sauter le plus loin possible",
+                    "Atterrir en douceur et stabiliser",
+                ],
+                mistakes: &[
+                    "Genoux qui rentrent à la réception",
+                    "Atterrir sur les talons",
+                ],
+                breathing: "Expirer pendant le saut",
+            },
+        ),
+        (
+            "box-jump",
+            Guide {
+                setup: "Face à la caisse, à un pas",
+                steps: &[
+                    "Lancer les bras et sauter sur la caisse",
+                    "Se redresser complètement puis redescendre en marchant",
+                ],
+                mistakes: &[
+                    "Redescendre en sautant en arrière",
+                    "Atterrir au bord de la caisse",
+                ],
+                breathing: "Expirer pendant le saut",
+            },
+        ),
+        (
+            "scissor-kick",
+            Guide {
+                setup: "Allongé sur le dos, mains sous les fessiers, jambes tendues",
+                steps: &[
+                    "Décoller les pieds du sol",
+                    "Croiser et décroiser rapidement les jambes",
+                ],
+                mistakes: &["Bas du dos qui se cambre"],
+                breathing: "Respirer régulièrement",
+            },
+        ),
+        (
+            "hip-thrust",
+            Guide {
+                setup: "Haut du dos sur un banc, genoux fléchis, pieds à plat",
+                steps: &[
+                    "Monter le bassin jusqu'à aligner épaules et genoux",
+                    "Serrer les fessiers puis redescendre",
+                ],
+                mistakes: &["Cambrer le bas du dos", "Pousser sur les pointes de pieds"],
+                breathing: "Expirer en montant",
+            },
+        ),
+        (
+            "jump-rope-double-unders",
+            Guide {
+                setup: "Coudes près du corps, corde derrière les talons",
+                steps: &[
+                    "Sauter un peu plus haut que d'habitude",
+                    "Faire passer la corde deux fois sous les pieds avec des poignets rapides",
+                ],
+                mistakes: &["Plier les jambes en équerre", "Tourner avec les bras"],
+                breathing: "Respirer entre les sauts à un rythme régulier",
+            },
+        ),
+        (
+            "jump-rope-criss-cross",
+            Guide {
+                setup: "Coudes près du corps, corde derrière les talons",
+                steps: &[
+                    "Croiser les bras devant le corps sur un tour",
+                    "Les décroiser au tour suivant",
+                ],
+                mistakes: &["Croiser seulement les mains", "Bras trop hauts"],
+                breathing: "Respirer à un rythme régulier",
+            },
+        ),
+        (
+            "jump-rope-high-knees",
+            Guide {
+                setup: "Coudes près du corps, corde derrière les talons",
+                steps: &[
+                    "Courir sur place au-dessus de la corde",
+                    "Monter les genoux à hauteur de hanches",
+                ],
+                mistakes: &["Genoux trop bas", "Se pencher en arrière"],
+                breathing: "Respirer au rythme des pas",
+            },
+        ),
+    ],
+};
+
+#[test]
+fn i18n_catalog_tests() {
+    use crate::item_list::ItemList;
+    use crate::tag::{Body, Equipment, Mouvement};
+    use strum::VariantArray;
+
+    for locale in Locale::VARIANTS {
+        for text in Text::VARIANTS {
+            assert!(!locale.text(*text).is_empty());
+        }
+        let Some(catalog) = locale.catalog() else {
+            continue;
+        };
+        for text in Text::VARIANTS {
+            assert!(
+                lookup(catalog.texts, *text).is_some(),
+                "{text:?} is not translated in {locale}"
+            );
+        }
+        for variant in ItemList::VARIANTS {
+            let item: Item = variant.clone().into();
+            assert!(
+                lookup(catalog.items, item.slug().as_str()).is_some(),
+                "{variant:?} name is not translated in {locale}"
+            );
+            if item.description().is_some() {
+                assert!(
+                    lookup(catalog.descriptions, item.slug().as_str()).is_some(),
+                    "{variant:?} description is not translated in {locale}"
+                );
+            }
+            if let Some(instructions) = item.instructions() {
+                assert!(
+                    catalog
+                        .instructions
+                        .iter()
+                        .any(|(slug, _)| *slug == item.slug()),
+                    "{variant:?} instructions are not translated in {locale}"
+                );
+                let translated = locale.item_instructions(&item).unwrap();
+                assert_eq!(translated.setup().is_some(), instructions.setup().is_some());
+                assert_eq!(translated.steps().len(), instructions.steps().len());
+                assert_eq!(translated.mistakes().len(), instructions.mistakes().len());
+                assert_eq!(
+                    translated.breathing().is_some(),
+                    instructions.breathing().is_some()
+                );
+            }
+        }
+        let tags = ItemList::tags()
+            .into_iter()
+            .chain(Body::VARIANTS.iter().map(|tag| Tag::from(*tag)))
+            .chain(Mouvement::VARIANTS.iter().map(|tag| Tag::from(*tag)))
+            .chain(Equipment::VARIANTS.iter().map(|tag| Tag::from(*tag)));
+        for tag in tags {
+            assert!(
+                lookup(catalog.tags, tag.slug().as_str()).is_some(),
+                "{tag} tag is not translated in {locale}"
+            );
+        }
+    }
+}
+
+#[test]
+fn i18n_tests() {
+    use crate::item_list::ItemList;
+
+    let push_up: Item = ItemList::PushUp.into();
+    assert_eq!(Locale::En.item_name(&push_up), "Push Up");
+    assert_eq!(Locale::Fr.item_name(&push_up), "Pompe");
+    assert_eq!(
+        Locale::Fr.item(&push_up),
+        format!("{}Pompe", push_up.icon().unwrap())
+    );
+    assert_eq!(Locale::Fr.tag(&Tag::Rest), "Repos");
+    assert_eq!(Locale::En.tag(&Tag::Rest), "Rest");
+    assert_eq!(Locale::Fr.weekday(Weekday::Wednesday), "Mercredi");
+    assert_eq!(
+        Locale::En.weekday(Weekday::Wednesday),
+        Weekday::Wednesday.to_string()
+    );
+    assert_eq!(Locale::En.text(Text::Prepare), "Prepare");
+    assert_eq!(Locale::Fr.text(Text::Prepare), "Préparation");
+    assert_eq!(Locale::from_language("fr-CA"), Locale::Fr);
+    assert_eq!(Locale::from_language("de"), Locale::En);
+
+    let combo: Item = "1-2".parse::<crate::combo::Combo>().unwrap().into();
+    assert_eq!(Locale::Fr.item_name(&combo), combo.name());
+}
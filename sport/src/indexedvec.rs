@@ -1,4 +1,5 @@
 use derive_more::{Deref, IntoIterator};
+use rand::Rng;
 use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 
@@ -80,11 +81,17 @@ where
         self.index = None;
     }
     pub fn shuffle(&mut self) {
-        self.store.shuffle(&mut rand::rng())
+        self.shuffle_with(&mut rand::rng())
     }
-    pub fn shuffled(&mut self) -> Vec<T> {
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.store.shuffle(rng)
+    }
+    pub fn shuffled(&self) -> Vec<T> {
+        self.shuffled_with(&mut rand::rng())
+    }
+    pub fn shuffled_with<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<T> {
         let mut store = self.store.clone();
-        store.shuffle(&mut rand::rng());
+        store.shuffle(rng);
         store
     }
     pub fn current(&self) -> Option<&T> {
@@ -213,3 +220,18 @@ fn indexedvec_simple_tests() {
     assert_eq!(simple.current(), Some(&false));
     assert!(!simple.last());
 }
+
+#[test]
+fn indexedvec_shuffle_tests() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    let values = IndexedVec::from((0..32).collect::<Vec<_>>());
+    let first = values.shuffled_with(&mut StdRng::seed_from_u64(3));
+    let second = values.shuffled_with(&mut StdRng::seed_from_u64(3));
+    assert_eq!(first, second);
+    assert_ne!(first, *values);
+
+    let mut shuffled = values.clone();
+    shuffled.shuffle_with(&mut StdRng::seed_from_u64(3));
+    assert_eq!(*shuffled, first);
+}
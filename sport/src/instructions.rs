@@ -0,0 +1,59 @@
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+
+fn lines(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(ToString::to_string).collect()
+}
+
+/// Coaching content of an exercise
+#[derive(Default, Clone, Debug, Eq, PartialEq, Hash, Builder, Serialize, Deserialize)]
+pub struct Instructions {
+    /// Starting position
+    #[builder(into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    setup: Option<String>,
+    #[builder(default, with = |steps: &[&str]| lines(steps))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    steps: Vec<String>,
+    /// Common mistakes to avoid
+    #[builder(default, with = |mistakes: &[&str]| lines(mistakes))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    mistakes: Vec<String>,
+    #[builder(into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    breathing: Option<String>,
+}
+
+impl Instructions {
+    pub fn setup(&self) -> Option<&str> {
+        self.setup.as_deref()
+    }
+    pub fn steps(&self) -> &[String] {
+        &self.steps
+    }
+    pub fn mistakes(&self) -> &[String] {
+        &self.mistakes
+    }
+    pub fn breathing(&self) -> Option<&str> {
+        self.breathing.as_deref()
+    }
+}
+
+#[test]
+fn instructions_tests() {
+    let instructions = Instructions::builder()
+        .setup("Hands under the shoulders")
+        .steps(&["Lower the chest", "Push back up"])
+        .breathing("Inhale down, exhale up")
+        .build();
+    assert_eq!(instructions.setup(), Some("Hands under the shoulders"));
+    assert_eq!(instructions.steps().len(), 2);
+    assert!(instructions.mistakes().is_empty());
+
+    let json = serde_json::to_string(&instructions).unwrap();
+    assert!(!json.contains("mistakes"));
+    assert_eq!(
+        serde_json::from_str::<Instructions>(&json).unwrap(),
+        instructions
+    );
+}
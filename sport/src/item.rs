@@ -1,4 +1,7 @@
-use crate::tag::{Difficulty, Tag, Tags};
+use crate::activity::Activity;
+use crate::effort::Met;
+use crate::instructions::Instructions;
+use crate::tag::{Difficulty, Equipment, Tag, Tags};
 use crate::workout::Workout;
 use bon::Builder;
 use derive_more::Display;
@@ -22,6 +25,24 @@ pub struct Item {
     difficulty: Option<Difficulty>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     icon: Option<char>,
+    /// Explicit effort, estimated from the tags otherwise
+    #[builder(into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    met: Option<Met>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    instructions: Option<Instructions>,
+    /// Inline SVG drawing of the exercise
+    #[builder(into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pictogram: Option<String>,
+    /// Slugs of the direct easier variants
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    easier: Vec<String>,
+    /// Slugs of the direct harder variants
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    harder: Vec<String>,
 }
 
 impl Item {
@@ -40,6 +61,43 @@ impl Item {
     pub fn description(&self) -> &Option<String> {
         &self.description
     }
+    pub fn equipment(&self) -> Vec<Equipment> {
+        self.tags
+            .iter()
+            .filter_map(|tag| match tag {
+                Tag::Equipment(Equipment::None) => None,
+                Tag::Equipment(equipment) => Some(*equipment),
+                _ => None,
+            })
+            .collect_vec()
+    }
+    pub fn level(&self) -> Option<Difficulty> {
+        self.difficulty
+    }
+    pub fn met(&self) -> Met {
+        self.met.unwrap_or_else(|| Met::from_tags(&self.tags))
+    }
+    pub fn activity(&self) -> Activity {
+        Activity::from_tags(&self.tags)
+    }
+    pub fn instructions(&self) -> Option<&Instructions> {
+        self.instructions.as_ref()
+    }
+    pub fn pictogram(&self) -> Option<&str> {
+        self.pictogram.as_deref()
+    }
+    pub fn easier(&self) -> &[String] {
+        &self.easier
+    }
+    pub fn harder(&self) -> &[String] {
+        &self.harder
+    }
+    #[must_use]
+    pub fn with_variants(mut self, easier: Vec<String>, harder: Vec<String>) -> Self {
+        self.easier = easier;
+        self.harder = harder;
+        self
+    }
     pub fn is_rest(&self) -> bool {
         self.tags.contains(&Tag::Rest)
     }
@@ -1,4 +1,6 @@
 use crate::{
+    effort::Met,
+    instructions::Instructions,
     item::Item,
     tag::{Body, Boxing, Difficulty, Equipment, Mouvement, Tag},
     workout::Workout,
@@ -8,7 +10,7 @@ use std::collections::{HashMap, HashSet};
 use strum::VariantArray;
 use strum_macros::VariantArray;
 
-#[derive(Clone, VariantArray)]
+#[derive(Clone, Debug, PartialEq, Eq, VariantArray)]
 pub enum ItemList {
     WarmUp,
     Workout,
@@ -88,6 +90,40 @@ impl ItemList {
         tags.sort_by_key(|t| t.slug());
         tags
     }
+    pub fn find(slug: &str) -> Option<Item> {
+        Self::items().into_iter().find(|item| item.slug() == slug)
+    }
+    /// Direct harder variants, the progression graph goes from easier to harder items
+    pub fn harder(&self) -> Vec<Self> {
+        match self {
+            Self::Walk => vec![Self::Run],
+            Self::Run => vec![Self::Sprint],
+            Self::Plank => vec![Self::PlankShoulderTap],
+            Self::PlankShoulderTap => vec![Self::CommandoPlank],
+            Self::Squat => vec![Self::SquatJump],
+            Self::SquatJump => vec![Self::SquatBulgarian],
+            Self::SquatBulgarian => vec![Self::SquatSingleLeg],
+            Self::PushUp | Self::Burpee => vec![Self::BurpeePushUp],
+            Self::BurpeePushUp => vec![Self::BurpeeNavySeal],
+            Self::LungeReverse => vec![Self::Lunge],
+            Self::Lunge => vec![Self::LungeWalking],
+            Self::LungeWalking => vec![Self::LungeJumping],
+            Self::Jump => vec![Self::JumpForward],
+            Self::JumpForward => vec![Self::BoxJump],
+            Self::JumpRope => vec![Self::JumpRopeHighKnees],
+            Self::JumpRopeHighKnees => vec![Self::JumpRopeCrissCross],
+            Self::JumpRopeCrissCross => vec![Self::JumpRopeDoubleUnders],
+            _ => vec![],
+        }
+    }
+    /// Direct easier variants, inverse of `harder`
+    pub fn easier(&self) -> Vec<Self> {
+        Self::VARIANTS
+            .iter()
+            .filter(|variant| variant.harder().contains(self))
+            .cloned()
+            .collect_vec()
+    }
     pub fn items() -> Vec<Item> {
         let mut items: Vec<_> = Self::VARIANTS
             .iter()
@@ -125,6 +161,19 @@ impl ItemList {
 #[allow(clippy::from_over_into)]
 impl Into<Item> for ItemList {
     fn into(self) -> Item {
+        let slugs = |variants: Vec<Self>| {
+            variants
+                .into_iter()
+                .map(|variant| variant.item().slug())
+                .collect_vec()
+        };
+        let (easier, harder) = (slugs(self.easier()), slugs(self.harder()));
+        self.item().with_variants(easier, harder)
+    }
+}
+
+impl ItemList {
+    fn item(self) -> Item {
         match self {
             Self::WarmUp => Item::builder()
                 .name("🔥Warm Up")
@@ -149,186 +198,498 @@ impl Into<Item> for ItemList {
                 .build(),
             Self::Walk => Item::builder()
                 .name("Walk")
+                .met(3.5)
                 .icon('🧍')
                 .tags(bon::vec![Mouvement::Footwork])
                 .difficulty(Difficulty::Easy)
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, shoulders relaxed")
+                        .steps(&["Roll from heel to toe", "Swing the arms naturally"])
+                        .mistakes(&["Looking down at the feet"])
+                        .breathing("Breathe naturally")
+                        .build(),
+                )
                 .build(),
             Self::Run => Item::builder()
                 .name("Run")
+                .met(9.8)
                 .tags(bon::vec![Mouvement::Footwork])
                 .difficulty(Difficulty::Medium)
                 .icon('🏃')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, slight forward lean")
+                        .steps(&["Land under the hips on the middle of the foot", "Drive the arms back and forth, not across"])
+                        .mistakes(&["Overstriding", "Tense shoulders"])
+                        .breathing("Breathe in rhythm with the steps")
+                        .build(),
+                )
                 .build(),
             Self::Sprint => Item::builder()
                 .name("Sprint")
+                .met(14.5)
                 .tags(bon::vec![Mouvement::Footwork])
                 .difficulty(Difficulty::Hard)
                 .icon('🏃')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Athletic stance, weight on the front foot")
+                        .steps(&["Drive the knees high and push the floor back", "Pump the arms hard from the shoulders"])
+                        .mistakes(&["Leaning back", "Landing on the heels"])
+                        .breathing("Short powerful breaths")
+                        .build(),
+                )
                 .build(),
             Self::SideSteps => Item::builder()
                 .name("Side Steps")
                 .tags(bon::vec![Mouvement::Footwork])
                 .icon('👯')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Knees slightly bent, guard up")
+                        .steps(&["Step sideways with the leading foot", "Bring the other foot back to shoulder width"])
+                        .mistakes(&["Crossing the feet", "Standing upright"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::Plank => Item::builder()
                 .name("Plank")
                 .tags(bon::vec![Mouvement::Stationary, Body::Core, Body::Shoulder])
                 .icon('🚪')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Forearms on the floor, elbows under the shoulders")
+                        .steps(&[
+                            "Lift the hips to align shoulders, hips and heels",
+                            "Squeeze the glutes and the abs, hold the position",
+                        ])
+                        .mistakes(&["Hips too high", "Sagging lower back", "Holding the breath"])
+                        .breathing("Breathe slowly and steadily")
+                        .build(),
+                )
+                .pictogram(include_str!("../pictograms/plank.svg"))
                 .build(),
             Self::PlankShoulderTap => Item::builder()
                 .name("Plank Shoulder Tap")
                 .tags(bon::vec![Mouvement::Dynamic, Body::Core, Body::Shoulder])
                 .icon('🚪')
+                .instructions(
+                    Instructions::builder()
+                        .setup("High plank, feet hip width apart")
+                        .steps(&["Tap the opposite shoulder with one hand", "Put it back and switch hands"])
+                        .mistakes(&["Hips swaying from side to side"])
+                        .breathing("Exhale on each tap")
+                        .build(),
+                )
                 .build(),
             Self::SidePlank => Item::builder()
                 .name("Side Plank")
                 .tags(bon::vec![Mouvement::Stationary, Body::Obliques])
                 .icon('🚪')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Lie on the side, elbow under the shoulder, legs stacked")
+                        .steps(&["Lift the hips until the body is straight", "Hold, then switch sides"])
+                        .mistakes(&["Hips dropping", "Shoulder shrugged to the ear"])
+                        .breathing("Breathe slowly and steadily")
+                        .build(),
+                )
                 .build(),
             Self::CommandoPlank => Item::builder()
                 .name("Commando Plank")
                 .tags(bon::vec![Mouvement::Dynamic])
                 .icon('🚪')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Forearm plank, feet hip width apart")
+                        .steps(&["Push up onto one hand, then the other", "Lower back down one forearm at a time"])
+                        .mistakes(&["Hips rocking", "Always leading with the same arm"])
+                        .breathing("Exhale while pushing up")
+                        .build(),
+                )
                 .build(),
             Self::HeadRotation => Item::builder()
                 .name("Head Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, shoulders down")
+                        .steps(&["Slowly roll the head in a circle", "Reverse the direction"])
+                        .mistakes(&["Moving too fast"])
+                        .breathing("Breathe slowly")
+                        .build(),
+                )
                 .build(),
             Self::ShoulderRotation => Item::builder()
                 .name("Shoulder Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, arms relaxed")
+                        .steps(&["Roll the shoulders backwards in large circles", "Then roll them forwards"])
+                        .mistakes(&["Shrugging without rolling"])
+                        .breathing("Breathe slowly")
+                        .build(),
+                )
                 .build(),
             Self::ArmRotation => Item::builder()
                 .name("Arms Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand with feet apart, arms out to the sides")
+                        .steps(&["Draw circles with straight arms", "Grow the circles, then reverse"])
+                        .mistakes(&["Bending the elbows"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::ElbowRotation => Item::builder()
                 .name("Elbows Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Arms out in front, upper arms still")
+                        .steps(&["Circle the forearms around the elbows", "Reverse the direction"])
+                        .mistakes(&["Moving the shoulders"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::WristRotation => Item::builder()
                 .name("Wrists Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Interlace the fingers in front of the chest")
+                        .steps(&["Roll the wrists in circles", "Reverse the direction"])
+                        .mistakes(&["Forcing the range"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::HipRotation => Item::builder()
                 .name("Hips Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Hands on the hips, feet shoulder width apart")
+                        .steps(&["Draw large circles with the hips", "Reverse the direction"])
+                        .mistakes(&["Moving the shoulders instead of the hips"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::KneeRotation => Item::builder()
                 .name("Knees Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Feet together, hands on the knees")
+                        .steps(&["Circle the knees together", "Reverse the direction"])
+                        .mistakes(&["Locking the knees"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::AnkleRotation => Item::builder()
                 .name("Feet Rotation")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Rotation])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand on one foot, toes of the other on the floor")
+                        .steps(&["Circle the ankle", "Reverse the direction, then switch feet"])
+                        .mistakes(&["Rushing the circles"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::HeelRaise => Item::builder()
                 .name("Heels Raises")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Stationary])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand with feet hip width apart")
+                        .steps(&["Rise onto the toes", "Lower the heels slowly"])
+                        .mistakes(&["Rolling onto the outer edge of the feet"])
+                        .breathing("Exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::Inchworm => Item::builder()
                 .name("Inchworm")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Stretching])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, feet hip width apart")
+                        .steps(&["Fold forward and put the hands on the floor", "Walk the hands out to a plank", "Walk the hands back and stand up"])
+                        .mistakes(&["Bending the knees a lot", "Sagging hips in the plank"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::LegSwingFront => Item::builder()
                 .name("Leg Swings Front")
                 .tags(bon::vec![Tag::WarmUp])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand on one leg, hand on a wall if needed")
+                        .steps(&["Swing the free leg forward and back", "Switch legs"])
+                        .mistakes(&["Rounding the back"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::LegSwingSide => Item::builder()
                 .name("Leg Swings Side")
                 .tags(bon::vec![Tag::WarmUp])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand on one leg, facing a wall")
+                        .steps(&["Swing the free leg from side to side", "Switch legs"])
+                        .mistakes(&["Twisting the hips"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::LegTouchToe => Item::builder()
                 .name("Single Leg Touch Toes")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Stretching])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand on one leg, the other slightly behind")
+                        .steps(&["Hinge forward and reach for the toes", "Stand back up, then switch legs"])
+                        .mistakes(&["Rounding the back", "Locking the standing knee"])
+                        .breathing("Exhale while reaching")
+                        .build(),
+                )
                 .build(),
             Self::Windmill => Item::builder()
                 .name("Windmill")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Stretching])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Feet wide apart, arms out to the sides")
+                        .steps(&["Bend and touch the opposite foot", "Stand up and switch sides"])
+                        .mistakes(&["Bending the arms"])
+                        .breathing("Exhale while reaching down")
+                        .build(),
+                )
                 .build(),
             Self::ButtKicks => Item::builder()
                 .name("Butt Kicks")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Dynamic])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, hands behind the glutes")
+                        .steps(&["Jog in place, heels towards the glutes", "Stay light on the balls of the feet"])
+                        .mistakes(&["Leaning forward"])
+                        .breathing("Breathe in rhythm")
+                        .build(),
+                )
                 .build(),
             Self::HighKnees => Item::builder()
                 .name("High Knees")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Dynamic])
                 .icon('⚙')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, arms bent")
+                        .steps(&["Run in place driving the knees to hip height", "Pump the arms"])
+                        .mistakes(&["Leaning back", "Knees too low"])
+                        .breathing("Breathe in rhythm")
+                        .build(),
+                )
                 .build(),
             Self::JumpingJack => Item::builder()
                 .name("Jumping Jack")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Dynamic])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand with feet together, arms along the body")
+                        .steps(&[
+                            "Jump the feet apart while raising the arms overhead",
+                            "Jump back to the starting position",
+                        ])
+                        .mistakes(&["Landing on the heels", "Stiff knees"])
+                        .breathing("Keep a regular rhythm, exhale every two jumps")
+                        .build(),
+                )
+                .pictogram(include_str!("../pictograms/jumping-jack.svg"))
                 .build(),
             Self::MountainClimber => Item::builder()
                 .name("Mountain Climber")
                 .tags(bon::vec![Tag::WarmUp, Mouvement::Dynamic])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("High plank, hands under the shoulders")
+                        .steps(&["Drive one knee towards the chest", "Switch legs quickly"])
+                        .mistakes(&["Hips bouncing up and down", "Hands in front of the shoulders"])
+                        .breathing("Short regular breaths")
+                        .build(),
+                )
                 .build(),
             Self::Squat => Item::builder()
                 .name("Squat")
                 .tags(bon::vec![Mouvement::Dynamic])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Feet shoulder width apart, toes slightly turned out")
+                        .steps(&[
+                            "Push the hips back and bend the knees",
+                            "Go down until the thighs are parallel to the floor",
+                            "Drive through the heels to stand up",
+                        ])
+                        .mistakes(&["Knees caving in", "Heels leaving the floor", "Rounded back"])
+                        .breathing("Inhale on the way down, exhale on the way up")
+                        .build(),
+                )
+                .pictogram(include_str!("../pictograms/squat.svg"))
                 .build(),
             Self::SquatBulgarian => Item::builder()
                 .name("Squat Bulgarian")
-                .tags(bon::vec![Mouvement::Dynamic])
+                .tags(bon::vec![Mouvement::Dynamic, Equipment::Bench])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Back foot on a bench, front foot forward")
+                        .steps(&["Lower the back knee towards the floor", "Push through the front heel to come up"])
+                        .mistakes(&["Front knee caving in", "Front foot too close to the bench"])
+                        .breathing("Inhale on the way down, exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::SquatSingleLeg => Item::builder()
                 .name("Squat Single Leg")
                 .tags(bon::vec![Mouvement::Dynamic])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand on one leg, the other held in front")
+                        .steps(&["Sit the hips back and down on one leg", "Drive back up without touching down"])
+                        .mistakes(&["Knee caving in", "Losing balance backwards"])
+                        .breathing("Inhale on the way down, exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::SquatJump => Item::builder()
                 .name("Squat Jump")
                 .tags(bon::vec![Mouvement::Dynamic])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Feet shoulder width apart")
+                        .steps(&["Squat down", "Jump explosively", "Land softly back into the squat"])
+                        .mistakes(&["Landing with stiff legs", "Knees caving in"])
+                        .breathing("Exhale on the jump")
+                        .build(),
+                )
                 .build(),
             Self::SquatSumo => Item::builder()
                 .name("Squat Sumo")
                 .tags(bon::vec![Mouvement::Dynamic])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Feet wide apart, toes turned out")
+                        .steps(&["Lower the hips between the heels", "Push the knees out and stand up"])
+                        .mistakes(&["Knees caving in", "Leaning forward"])
+                        .breathing("Inhale on the way down, exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::PushUp => Item::builder()
                 .name("Push Up")
                 .tags(bon::vec![Mouvement::Dynamic, Mouvement::Strength])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Hands slightly wider than the shoulders, body straight from head to heels")
+                        .steps(&[
+                            "Lower the chest close to the floor, elbows at 45 degrees",
+                            "Push the floor away until the arms are straight",
+                        ])
+                        .mistakes(&["Sagging hips", "Flared elbows", "Half range of motion"])
+                        .breathing("Inhale on the way down, exhale while pushing")
+                        .build(),
+                )
+                .pictogram(include_str!("../pictograms/push-up.svg"))
                 .build(),
             Self::PullUp => Item::builder()
                 .name("Pull Up")
-                .tags(bon::vec![Mouvement::Dynamic, Mouvement::Strength])
+                .tags(bon::vec![
+                    Mouvement::Dynamic,
+                    Mouvement::Strength,
+                    Equipment::PullUpBar
+                ])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Hang from the bar, hands shoulder width apart")
+                        .steps(&["Pull the chest towards the bar", "Lower down with control to straight arms"])
+                        .mistakes(&["Swinging the legs", "Half range of motion"])
+                        .breathing("Exhale while pulling")
+                        .build(),
+                )
                 .build(),
             Self::SpeedStep => Item::builder()
                 .name("Speed Step")
                 .tags(bon::vec![Mouvement::Dynamic, Mouvement::Footwork])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Knees slightly bent, on the balls of the feet")
+                        .steps(&["Tap the feet as fast as possible", "Keep the steps small"])
+                        .mistakes(&["Standing on the heels"])
+                        .breathing("Short regular breaths")
+                        .build(),
+                )
                 .build(),
             Self::SkatingStep => Item::builder()
                 .name("Skating Step")
                 .tags(bon::vec![Mouvement::Dynamic, Mouvement::Footwork])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand on one leg, knee bent")
+                        .steps(&["Leap sideways onto the other leg", "Swing the rear leg behind and switch"])
+                        .mistakes(&["Landing with a stiff knee"])
+                        .breathing("Exhale on each leap")
+                        .build(),
+                )
                 .build(),
             Self::AlternateStepUp => Item::builder()
                 .name("Alternate Step")
                 .tags(bon::vec![Mouvement::Dynamic, Mouvement::Footwork])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Face a step or a low box")
+                        .steps(&["Step up with one foot, then the other", "Step down and lead with the other foot"])
+                        .mistakes(&["Pushing off the back foot"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::Lunge => Item::builder()
                 .name("Lunge")
@@ -338,6 +699,19 @@ impl Into<Item> for ItemList {
                     Mouvement::Strength
                 ])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, feet hip width apart")
+                        .steps(&[
+                            "Step forward and lower the back knee towards the floor",
+                            "Keep the front knee above the ankle",
+                            "Push through the front heel to come back",
+                        ])
+                        .mistakes(&["Front knee past the toes", "Leaning forward"])
+                        .breathing("Inhale on the way down, exhale on the way up")
+                        .build(),
+                )
+                .pictogram(include_str!("../pictograms/lunge.svg"))
                 .build(),
             Self::LungeReverse => Item::builder()
                 .name("Lunge Reverse")
@@ -347,6 +721,14 @@ impl Into<Item> for ItemList {
                     Mouvement::Balance,
                     Mouvement::Strength
                 ])
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, feet hip width apart")
+                        .steps(&["Step back and lower the back knee towards the floor", "Push through the front heel to come back"])
+                        .mistakes(&["Leaning forward", "Front knee caving in"])
+                        .breathing("Inhale on the way down, exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::LungeJumping => Item::builder()
                 .name("Lunge Jump")
@@ -357,6 +739,14 @@ impl Into<Item> for ItemList {
                     Mouvement::Strength
                 ])
                 .difficulty(Difficulty::Medium)
+                .instructions(
+                    Instructions::builder()
+                        .setup("Lunge position, back knee off the floor")
+                        .steps(&["Jump and switch legs in the air", "Land softly back into the lunge"])
+                        .mistakes(&["Landing with stiff legs", "Front knee past the toes"])
+                        .breathing("Exhale on the jump")
+                        .build(),
+                )
                 .build(),
             Self::LungeWalking => Item::builder()
                 .name("Lunge Walking")
@@ -366,6 +756,14 @@ impl Into<Item> for ItemList {
                     Mouvement::Strength
                 ])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand tall, feet hip width apart")
+                        .steps(&["Lunge forward", "Bring the back foot through into the next lunge"])
+                        .mistakes(&["Steps too short", "Leaning forward"])
+                        .breathing("Inhale on the way down, exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::Burpee => Item::builder()
                 .name("Burpee")
@@ -376,6 +774,19 @@ impl Into<Item> for ItemList {
                 ])
                 .icon('💓')
                 .difficulty(Difficulty::Medium)
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand with feet shoulder width apart")
+                        .steps(&[
+                            "Squat down and put the hands on the floor",
+                            "Jump the feet back into a plank",
+                            "Jump the feet back to the hands",
+                            "Jump up with the arms overhead",
+                        ])
+                        .mistakes(&["Sagging hips in the plank", "Landing with stiff legs"])
+                        .breathing("Exhale on the jump")
+                        .build(),
+                )
                 .build(),
             Self::BurpeePushUp => Item::builder()
                 .name("Burpee Push Up")
@@ -385,6 +796,14 @@ impl Into<Item> for ItemList {
                     Mouvement::Stamina
                 ])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand with feet shoulder width apart")
+                        .steps(&["Squat down and jump the feet back into a plank", "Do a push up", "Jump the feet back to the hands and jump up"])
+                        .mistakes(&["Sagging hips in the push up", "Skipping the push up"])
+                        .breathing("Exhale on the push and on the jump")
+                        .build(),
+                )
                 .build(),
             Self::BurpeeNavySeal => Item::builder()
                 .name("Burpee Navy Seal")
@@ -394,11 +813,27 @@ impl Into<Item> for ItemList {
                     Mouvement::Stamina
                 ])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand with feet shoulder width apart")
+                        .steps(&["Jump back into a plank and do a push up", "Bring each knee to the opposite elbow, then a push up after each", "Jump the feet back to the hands and jump up"])
+                        .mistakes(&["Rushing the knee drives", "Sagging hips"])
+                        .breathing("Exhale on each effort")
+                        .build(),
+                )
                 .build(),
             Self::Jump => Item::builder()
                 .name("Jumps")
                 .tags(bon::vec![Mouvement::Dynamic, Mouvement::Stamina])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Feet hip width apart, knees soft")
+                        .steps(&["Swing the arms and jump straight up", "Land softly on the balls of the feet"])
+                        .mistakes(&["Landing with stiff legs"])
+                        .breathing("Exhale on the jump")
+                        .build(),
+                )
                 .build(),
             Self::JumpForward => Item::builder()
                 .name("Jumps Forward")
@@ -408,40 +843,108 @@ impl Into<Item> for ItemList {
                     Mouvement::Balance
                 ])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Feet hip width apart, knees bent")
+                        .steps(&["Swing the arms and jump forward as far as possible", "Land softly and hold the landing"])
+                        .mistakes(&["Knees caving in on landing", "Landing on the heels"])
+                        .breathing("Exhale on the jump")
+                        .build(),
+                )
                 .build(),
             Self::BoxJump => Item::builder()
                 .name("Box Jump")
                 .tags(bon::vec![Body::Abs, Body::Core, Equipment::Box])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Stand facing the box, one step away")
+                        .steps(&["Swing the arms and jump onto the box", "Stand up fully, then step down"])
+                        .mistakes(&["Jumping down backwards", "Landing on the edge of the box"])
+                        .breathing("Exhale on the jump")
+                        .build(),
+                )
                 .build(),
             Self::Crunch => Item::builder()
                 .name("Crunches")
                 .tags(bon::vec![Body::Abs, Body::Core])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Lie on the back, knees bent, feet flat")
+                        .steps(&[
+                            "Curl the shoulders off the floor using the abs",
+                            "Lower back down with control",
+                        ])
+                        .mistakes(&["Pulling on the neck", "Using momentum"])
+                        .breathing("Exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::ScissorKick => Item::builder()
                 .name("Scissor Kick")
                 .tags(bon::vec![Body::Abs, Body::Hip, Body::Core])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Lie on the back, hands under the glutes, legs straight")
+                        .steps(&["Lift the feet off the floor", "Cross and uncross the legs quickly"])
+                        .mistakes(&["Lower back arching off the floor"])
+                        .breathing("Breathe steadily")
+                        .build(),
+                )
                 .build(),
             Self::HipThrust => Item::builder()
                 .name("Hip Thrust")
                 .tags(bon::vec![Body::Hip, Body::Core, Body::Buttocks])
                 .icon('💓')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Upper back on a bench, knees bent, feet flat")
+                        .steps(&["Drive the hips up until the body is straight from shoulders to knees", "Squeeze the glutes, then lower down"])
+                        .mistakes(&["Arching the lower back", "Pushing through the toes"])
+                        .breathing("Exhale on the way up")
+                        .build(),
+                )
                 .build(),
             Self::BoxingRound => Item::builder()
                 .name("Boxing Round")
+                .met(Met::BOXING)
                 .icon('🥊')
                 .tags(bon::vec![Boxing::Round, Mouvement::Stamina, Body::Full])
+                .instructions(
+                    Instructions::builder()
+                        .setup("Guard up, chin down, lead foot forward")
+                        .steps(&[
+                            "Move on the balls of the feet",
+                            "Throw combinations and return to the guard",
+                            "Mix in defense and footwork",
+                        ])
+                        .mistakes(&["Dropping the hands", "Crossing the feet", "Standing still"])
+                        .breathing("Exhale sharply on every punch")
+                        .build(),
+                )
                 .build(),
             Self::JumpRope => Item::builder()
                 .name("Jump Rope")
+                .met(11.8)
                 .tags(bon::vec![
                     Mouvement::Dynamic,
                     Mouvement::Coordination,
                     Equipment::JumpRope,
                 ])
                 .icon('🪱')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Elbows close to the body, rope behind the heels")
+                        .steps(&[
+                            "Turn the rope with the wrists",
+                            "Jump just high enough for the rope to pass",
+                        ])
+                        .mistakes(&["Turning with the arms", "Jumping too high"])
+                        .breathing("Breathe through the nose at a steady pace")
+                        .build(),
+                )
                 .build(),
             Self::JumpRopeDoubleUnders => Item::builder()
                 .name("Jump Rope Double Unders")
@@ -452,6 +955,14 @@ impl Into<Item> for ItemList {
                 ])
                 .icon('🪱')
                 .difficulty(Difficulty::Medium)
+                .instructions(
+                    Instructions::builder()
+                        .setup("Elbows close to the body, rope behind the heels")
+                        .steps(&["Jump a bit higher than usual", "Spin the rope twice under the feet with fast wrists"])
+                        .mistakes(&["Piking the legs", "Turning with the arms"])
+                        .breathing("Breathe between jumps at a steady pace")
+                        .build(),
+                )
                 .build(),
             Self::JumpRopeCrissCross => Item::builder()
                 .name("Jump Rope Criss Cross")
@@ -462,6 +973,14 @@ impl Into<Item> for ItemList {
                 ])
                 .difficulty(Difficulty::Medium)
                 .icon('🪱')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Elbows close to the body, rope behind the heels")
+                        .steps(&["Cross the arms in front of the body on one turn", "Uncross them on the next turn"])
+                        .mistakes(&["Crossing only the hands", "Arms too high"])
+                        .breathing("Breathe at a steady pace")
+                        .build(),
+                )
                 .build(),
             Self::JumpRopeHighKnees => Item::builder()
                 .name("Jump Rope High Knees")
@@ -472,7 +991,69 @@ impl Into<Item> for ItemList {
                 ])
                 .difficulty(Difficulty::Medium)
                 .icon('🪱')
+                .instructions(
+                    Instructions::builder()
+                        .setup("Elbows close to the body, rope behind the heels")
+                        .steps(&["Run in place over the rope", "Drive the knees to hip height"])
+                        .mistakes(&["Knees too low", "Leaning back"])
+                        .breathing("Breathe in rhythm with the steps")
+                        .build(),
+                )
                 .build(),
         }
     }
 }
+
+#[test]
+fn item_list_progression_tests() {
+    let squat: Item = ItemList::Squat.into();
+    let squat_jump: Item = ItemList::SquatJump.into();
+    assert!(squat.easier().is_empty());
+    assert_eq!(squat.harder(), &[squat_jump.slug()]);
+    assert_eq!(squat_jump.easier(), &[squat.slug()]);
+    assert_eq!(
+        ItemList::BurpeePushUp.easier(),
+        vec![ItemList::PushUp, ItemList::Burpee]
+    );
+    for variant in ItemList::VARIANTS {
+        let item: Item = variant.clone().into();
+        for slug in item.easier().iter().chain(item.harder()) {
+            assert!(
+                ItemList::find(slug).is_some(),
+                "{slug} of {item} is unknown"
+            );
+        }
+        assert!(!variant.harder().contains(variant));
+    }
+}
+
+#[test]
+fn item_list_instructions_tests() {
+    // generic placeholders have nothing to coach
+    let generic = [
+        ItemList::WarmUp,
+        ItemList::Workout,
+        ItemList::Tabata,
+        ItemList::Rest,
+    ];
+    let pictograms = [
+        ItemList::Plank,
+        ItemList::JumpingJack,
+        ItemList::Squat,
+        ItemList::PushUp,
+        ItemList::Lunge,
+    ];
+    for variant in ItemList::VARIANTS {
+        let item: Item = variant.clone().into();
+        assert_eq!(
+            item.instructions().is_none(),
+            generic.contains(variant),
+            "{variant:?} instructions"
+        );
+        assert_eq!(
+            item.pictogram().is_some(),
+            pictograms.contains(variant),
+            "{variant:?} pictogram"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use crate::i18n::Text;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use strum_macros::VariantArray;
+
+/// Timer controls reachable from the keyboard or a presentation clicker
+#[derive(
+    Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, VariantArray,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    #[display("Start / pause")]
+    Toggle,
+    #[display("Previous workout")]
+    Previous,
+    #[display("Next workout")]
+    Next,
+    #[display("Restart workout")]
+    RestartWorkout,
+    #[display("Mute / unmute")]
+    Mute,
+    #[display("Ring")]
+    Ring,
+    #[display("Show / hide shortcuts")]
+    Help,
+}
+
+impl Action {
+    #[must_use]
+    pub fn text(&self) -> Text {
+        match self {
+            Self::Toggle => Text::StartPause,
+            Self::Previous => Text::PreviousWorkout,
+            Self::Next => Text::NextWorkout,
+            Self::RestartWorkout => Text::RestartWorkout,
+            Self::Mute => Text::MuteUnmute,
+            Self::Ring => Text::Ring,
+            Self::Help => Text::ShowHideShortcuts,
+        }
+    }
+}
+
+/// Key names follow the DOM `KeyboardEvent.key` values, letters are case insensitive
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KeyBindings(Vec<(String, Action)>);
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self(
+            [
+                (" ", Action::Toggle),
+                ("ArrowLeft", Action::Previous),
+                ("ArrowRight", Action::Next),
+                // page turners send page up and down
+                ("PageUp", Action::Previous),
+                ("PageDown", Action::Next),
+                ("r", Action::RestartWorkout),
+                ("m", Action::Mute),
+                ("b", Action::Ring),
+                ("?", Action::Help),
+            ]
+            .into_iter()
+            .map(|(key, action)| (key.to_string(), action))
+            .collect(),
+        )
+    }
+}
+
+fn normalize(key: &str) -> String {
+    if key.chars().count() == 1 {
+        key.to_lowercase()
+    } else {
+        key.to_string()
+    }
+}
+
+impl KeyBindings {
+    pub fn action(&self, key: &str) -> Option<Action> {
+        let key = normalize(key);
+        self.0
+            .iter()
+            .find(|(bound, _)| *bound == key)
+            .map(|(_, action)| *action)
+    }
+    /// Bind a key, replacing the keys of the action and the previous action of the key
+    pub fn bind(&mut self, key: &str, action: Action) {
+        self.unbind(key);
+        self.0.retain(|(_, bound)| *bound != action);
+        self.0.push((normalize(key), action));
+    }
+    pub fn unbind(&mut self, key: &str) {
+        let key = normalize(key);
+        self.0.retain(|(bound, _)| *bound != key);
+    }
+    pub fn keys(&self, action: Action) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(key, _)| key.as_str())
+            .collect()
+    }
+}
+
+/// Printable key name
+pub fn label(key: &str) -> &str {
+    match key {
+        " " => "Space",
+        "ArrowLeft" => "←",
+        "ArrowRight" => "→",
+        "ArrowUp" => "↑",
+        "ArrowDown" => "↓",
+        _ => key,
+    }
+}
+
+#[test]
+fn key_bindings_tests() {
+    use strum::VariantArray;
+
+    let mut bindings = KeyBindings::default();
+    assert_eq!(bindings.action(" "), Some(Action::Toggle));
+    assert_eq!(bindings.action("R"), Some(Action::RestartWorkout));
+    assert_eq!(bindings.action("PageDown"), Some(Action::Next));
+    assert_eq!(bindings.action("x"), None);
+    for action in Action::VARIANTS {
+        assert!(!bindings.keys(*action).is_empty(), "{action} has no key");
+    }
+
+    bindings.bind("X", Action::Ring);
+    assert_eq!(bindings.action("x"), Some(Action::Ring));
+    assert_eq!(bindings.action("b"), None);
+    assert_eq!(bindings.keys(Action::Ring), vec!["x"]);
+    bindings.bind("x", Action::Mute);
+    assert_eq!(bindings.keys(Action::Mute), vec!["x"]);
+    assert_eq!(bindings.action("m"), None);
+    assert!(bindings.keys(Action::Ring).is_empty());
+    bindings.unbind(" ");
+    assert_eq!(bindings.action(" "), None);
+    assert_eq!(label(" "), "Space");
+
+    let json = serde_json::to_string(&bindings).unwrap();
+    assert_eq!(
+        serde_json::from_str::<KeyBindings>(&json).unwrap(),
+        bindings
+    );
+}
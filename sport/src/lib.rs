@@ -1,16 +1,33 @@
+pub mod activity;
+pub mod card;
+pub mod combo;
+pub mod csv;
 pub mod defaults;
 pub mod duration;
+pub mod effort;
+pub mod equipment;
 pub mod errors;
 pub mod exercises;
+pub mod export;
+pub mod generator;
+pub mod heart_rate;
+pub mod i18n;
 pub mod indexedvec;
+pub mod instructions;
 pub mod item;
 pub mod item_list;
+pub mod keys;
 pub mod player;
+pub mod program;
+pub mod protocol;
 pub mod sequence;
+pub mod session;
 pub mod signal;
 pub mod sound;
 pub mod status;
 pub mod stopwatch;
+pub mod summary;
 pub mod tag;
+pub mod template;
 pub mod timer;
 pub mod workout;
@@ -13,3 +13,20 @@ impl std::fmt::Debug for dyn Player {
         Ok(())
     }
 }
+
+/// Plays nothing, for tests
+#[cfg(test)]
+pub struct Silent;
+
+#[cfg(test)]
+impl Player for Silent {
+    fn play(&self, _sound: &Sound) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+    fn muted(&self, _sound: &Sound) -> Option<Result<bool, ErrorKind>> {
+        None
+    }
+    fn unmute(&self, _sound: &Sound) -> Result<(), ErrorKind> {
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+use crate::defaults::{PROGRAMS, TEMPLATES, find};
+use crate::sequence::Sequence;
+use crate::template::{ParameterKind, Values};
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use slug::slugify;
+use std::collections::HashMap;
+use time::{Date, Weekday};
+
+/// A sequence or template planned on a weekday, its parameters progress every week
+#[derive(Clone, Debug, PartialEq, Eq, Builder, Serialize, Deserialize)]
+pub struct Planned {
+    weekday: Weekday,
+    /// Sequence or template slug
+    #[builder(into)]
+    slug: String,
+    /// Template parameters of the first week
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    values: Values,
+    /// Added to the values every week, negative to shorten rests
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    progression: HashMap<ParameterKind, i64>,
+}
+
+impl Planned {
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+    /// Template parameters of a zero based week, progressions start from the template defaults
+    pub fn values(&self, week: u32) -> Values {
+        let mut values = self.values.clone();
+        for (kind, step) in &self.progression {
+            let value = values.entry(*kind).or_insert_with(|| {
+                TEMPLATES
+                    .iter()
+                    .find(|template| template.slug() == self.slug)
+                    .map_or(0, |template| template.parameter(*kind).default())
+            });
+            *value = value.saturating_add_signed(step.saturating_mul(i64::from(week)));
+        }
+        values
+    }
+    pub fn sequence(&self, week: u32) -> Option<Sequence> {
+        find(&self.slug, &self.values(week))
+    }
+}
+
+/// A day of a program, weeks count from zero
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Day {
+    pub week: u32,
+    pub weekday: Weekday,
+}
+
+/// Several weeks of planned sequences
+#[derive(Clone, Debug, PartialEq, Eq, Builder, Serialize, Deserialize)]
+pub struct Program {
+    #[builder(into)]
+    name: String,
+    #[builder(into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    weeks: u32,
+    #[builder(default)]
+    days: Vec<Planned>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    icon: Option<char>,
+}
+
+impl std::fmt::Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.icon.unwrap_or('‎'), self.name)
+    }
+}
+
+impl Program {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn slug(&self) -> String {
+        slugify(&self.name)
+    }
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+    pub fn weeks(&self) -> u32 {
+        self.weeks
+    }
+    pub fn days(&self) -> &[Planned] {
+        &self.days
+    }
+    pub fn planned(&self, weekday: Weekday) -> Option<&Planned> {
+        self.days.iter().find(|planned| planned.weekday == weekday)
+    }
+    /// Every training day, week after week
+    pub fn schedule(&self) -> Vec<Day> {
+        (0..self.weeks)
+            .flat_map(|week| {
+                self.days.iter().map(move |planned| Day {
+                    week,
+                    weekday: planned.weekday,
+                })
+            })
+            .collect()
+    }
+    pub fn sequence(&self, day: Day) -> Option<Sequence> {
+        if day.week >= self.weeks {
+            return None;
+        }
+        self.planned(day.weekday)?.sequence(day.week)
+    }
+}
+
+/// Someone following a program since a date
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enrollment {
+    /// Program slug
+    program: String,
+    started: Date,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    completed: Vec<Day>,
+}
+
+impl Enrollment {
+    pub fn new(program: &Program, started: Date) -> Self {
+        Self {
+            program: program.slug(),
+            started,
+            completed: Vec::new(),
+        }
+    }
+    pub fn program(&self) -> Option<&'static Program> {
+        PROGRAMS
+            .iter()
+            .find(|program| program.slug() == self.program)
+    }
+    pub fn started(&self) -> Date {
+        self.started
+    }
+    /// Program day of a date, whether training is planned or not
+    pub fn day(&self, date: Date) -> Option<Day> {
+        let days = (date - self.started).whole_days();
+        let week = u32::try_from(days.div_euclid(7)).ok()?;
+        (week < self.program()?.weeks()).then_some(Day {
+            week,
+            weekday: date.weekday(),
+        })
+    }
+    pub fn date(&self, day: Day) -> Option<Date> {
+        let offset = i64::from(day.weekday.number_days_from_monday())
+            - i64::from(self.started.weekday().number_days_from_monday());
+        self.started.checked_add(time::Duration::days(
+            i64::from(day.week) * 7 + offset.rem_euclid(7),
+        ))
+    }
+    /// The sequence planned on a date, none on rest days
+    pub fn today(&self, date: Date) -> Option<(Day, Sequence)> {
+        let day = self.day(date)?;
+        Some((day, self.program()?.sequence(day)?))
+    }
+    pub fn is_completed(&self, day: Day) -> bool {
+        self.completed.contains(&day)
+    }
+    pub fn complete(&mut self, day: Day) {
+        if !self.is_completed(day) {
+            self.completed.push(day);
+        }
+    }
+    /// Completed and total training days
+    pub fn progress(&self) -> (usize, usize) {
+        let total = self.program().map_or(0, |program| program.schedule().len());
+        (self.completed.len(), total)
+    }
+    /// Mark the day of `date` as completed if `slug` is the sequence or template planned,
+    /// whatever its level, duration or equipment
+    pub fn record(&mut self, date: Date, slug: &str) -> bool {
+        let Some(day) = self.day(date) else {
+            return false;
+        };
+        let planned = self
+            .program()
+            .and_then(|program| program.planned(day.weekday))
+            .is_some_and(|planned| planned.slug() == slug);
+        if planned {
+            self.complete(day);
+        }
+        planned
+    }
+}
+
+#[test]
+fn program_tests() {
+    let program = Program::builder()
+        .name("Boxing conditioning")
+        .weeks(2)
+        .days(vec![
+            Planned::builder()
+                .weekday(Weekday::Monday)
+                .slug("boxing-rounds")
+                .values(Values::from([(ParameterKind::Rounds, 3)]))
+                .progression(HashMap::from([(ParameterKind::Rounds, 1)]))
+                .build(),
+            Planned::builder()
+                .weekday(Weekday::Thursday)
+                .slug("hiit")
+                .values(Values::from([(ParameterKind::Rest, 20)]))
+                .progression(HashMap::from([(ParameterKind::Rest, -5)]))
+                .build(),
+        ])
+        .build();
+    assert_eq!(program.schedule().len(), 4);
+    let monday = |week| Day {
+        week,
+        weekday: Weekday::Monday,
+    };
+    assert_eq!(program.days()[0].values(1)[&ParameterKind::Rounds], 4);
+    assert_eq!(program.days()[1].values(1)[&ParameterKind::Rest], 15);
+    // hiit works 20 seconds by default
+    assert_eq!(program.days()[1].values(0).get(&ParameterKind::Work), None);
+    let longer = Planned::builder()
+        .weekday(Weekday::Friday)
+        .slug("hiit")
+        .progression(HashMap::from([(ParameterKind::Work, 5)]))
+        .build();
+    assert_eq!(longer.values(2)[&ParameterKind::Work], 30);
+    let first = program.sequence(monday(0)).unwrap();
+    let second = program.sequence(monday(1)).unwrap();
+    assert!(second.total() > first.total());
+    assert!(program.sequence(monday(2)).is_none());
+    let tuesday = Day {
+        week: 0,
+        weekday: Weekday::Tuesday,
+    };
+    assert!(program.sequence(tuesday).is_none());
+
+    for program in PROGRAMS.iter() {
+        for day in program.schedule() {
+            assert!(
+                program.sequence(day).is_some(),
+                "{} {day:?}",
+                program.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn enrollment_tests() {
+    use time::macros::date;
+
+    let program = PROGRAMS.first().unwrap();
+    let planned = &program.days()[0];
+    // start on a wednesday to exercise weekday offsets
+    let started = date!(2024 - 01 - 03);
+    let mut enrollment = Enrollment::new(program, started);
+    assert_eq!(enrollment.program(), Some(program));
+    assert_eq!(enrollment.day(date!(2024 - 01 - 02)), None);
+    assert_eq!(
+        enrollment.day(date!(2024 - 01 - 10)),
+        Some(Day {
+            week: 1,
+            weekday: Weekday::Wednesday
+        })
+    );
+    let first = Day {
+        week: 0,
+        weekday: planned.weekday(),
+    };
+    let date = enrollment.date(first).unwrap();
+    assert!(date >= started && (date - started).whole_days() < 7);
+    assert_eq!(date.weekday(), planned.weekday());
+
+    let (day, sequence) = enrollment.today(date).unwrap();
+    assert_eq!(day, first);
+    assert!(!enrollment.record(date, Sequence::default().slug().as_str()));
+    // the timer renames a sequence run harder or shorter
+    assert_ne!(sequence.progress(1).slug(), planned.slug());
+    assert!(enrollment.record(date, planned.slug()));
+    assert!(enrollment.is_completed(first));
+    assert_eq!(enrollment.progress(), (1, program.schedule().len()));
+
+    let after = started
+        .checked_add(time::Duration::weeks(i64::from(program.weeks())))
+        .unwrap();
+    assert_eq!(enrollment.day(after), None);
+
+    let json = serde_json::to_string(&enrollment).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Enrollment>(&json).unwrap(),
+        enrollment
+    );
+}
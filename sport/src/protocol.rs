@@ -0,0 +1,112 @@
+use crate::sequence::Sequence;
+use crate::sound::Sound;
+use serde::{Deserialize, Serialize};
+
+/// Snapshot of a timer, enough for another device to mirror it
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimerState {
+    pub sequence: Sequence,
+    /// Current workout, `None` while preparing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+    /// Time left in the current workout or in the preparation
+    pub left: std::time::Duration,
+    pub running: bool,
+    /// Missing from older controllers, then inferred from the current workout
+    #[serde(default)]
+    pub phase: crate::timer::Phase,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// Owns the timer and sends its state
+    Controller,
+    /// Mirrors the controller
+    Follower,
+}
+
+/// Messages exchanged through the relay, as JSON text frames
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    /// First message of every client
+    Join {
+        room: String,
+        role: Role,
+    },
+    State(TimerState),
+    /// The controller emitted a sound
+    Ring {
+        sound: Sound,
+    },
+}
+
+impl Message {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("messages are always serializable")
+    }
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[test]
+fn protocol_tests() {
+    use crate::defaults::SEQUENCES;
+
+    let join = Message::Join {
+        room: "gym".into(),
+        role: Role::Follower,
+    };
+    assert_eq!(
+        join.to_json(),
+        r#"{"type":"join","room":"gym","role":"follower"}"#
+    );
+    assert_eq!(Message::from_json(&join.to_json()).unwrap(), join);
+
+    let ring = Message::Ring { sound: Sound::Bell };
+    assert_eq!(Message::from_json(&ring.to_json()).unwrap(), ring);
+
+    let state = Message::State(TimerState {
+        sequence: SEQUENCES.first().unwrap().clone(),
+        index: Some(1),
+        left: std::time::Duration::from_secs(12),
+        running: true,
+        phase: crate::timer::Phase::Resting,
+    });
+    let Message::State(parsed) = Message::from_json(&state.to_json()).unwrap() else {
+        panic!("state expected");
+    };
+    assert_eq!(parsed.index, Some(1));
+    assert_eq!(parsed.phase, crate::timer::Phase::Resting);
+    assert_eq!(parsed.sequence.name(), SEQUENCES.first().unwrap().name());
+    assert!(Message::from_json("{\"type\":\"unknown\"}").is_err());
+}
+
+#[test]
+fn protocol_follow_tests() {
+    use crate::defaults::SEQUENCES;
+    use crate::player::Silent;
+    use crate::signal::SoundSignal;
+    use crate::timer::Timer;
+
+    let sequence = SEQUENCES.first().unwrap();
+    let signal = SoundSignal::from_muted(true);
+    let mut controller = Timer::from_sequence(sequence, &signal, Box::new(Silent));
+    controller.toggle();
+    for _ in 0..20 {
+        controller.tick();
+    }
+    let mut follower = Timer::from_sequence(&Sequence::default(), &signal, Box::new(Silent));
+    let Message::State(state) =
+        Message::from_json(&Message::State(controller.state()).to_json()).unwrap()
+    else {
+        panic!("state expected");
+    };
+    follower.follow(&state);
+    assert_eq!(follower.state(), controller.state());
+    assert_eq!(follower.label(), controller.label());
+    assert_eq!(follower.left(), controller.left());
+    assert!(follower.status().running());
+}
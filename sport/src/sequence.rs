@@ -1,7 +1,9 @@
+use crate::combo::Combo;
 use crate::duration::DurationExt;
 use crate::exercises::Exercises;
 use crate::indexedvec::IndexedVec;
 use crate::item::Item;
+use crate::item_list::ItemList;
 use crate::sound::Sound;
 use crate::stopwatch::Stopwatch;
 use crate::tag::{Difficulty, Tag};
@@ -9,7 +11,8 @@ use crate::workout::Workout;
 use bon::{Builder, bon};
 use derive_more::{Deref, DerefMut, Display};
 use itertools::Itertools;
-use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
 use slug::slugify;
 use std::ops::Not;
@@ -47,9 +50,19 @@ pub struct Sequence {
     #[serde(default, skip_serializing_if = "<&bool>::not")]
     shuffleable: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     difficulty: Option<Difficulty>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     icon: Option<char>,
+    /// Net number of `harder` steps, negative for `easier` ones
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "is_zero")]
+    level: i8,
+}
+
+fn is_zero(level: &i8) -> bool {
+    *level == 0
 }
 
 type Rounds = usize;
@@ -61,24 +74,27 @@ impl Sequence {
     pub fn random(
         name: &str,
         description: Option<&str>,
-        mut workouts: Vec<Workout>,
+        workouts: Vec<Workout>,
         rest: std::time::Duration,
         sound: &Sound,
         difficulty: Option<Difficulty>,
         icon: Option<char>,
+        seed: Option<u64>,
     ) -> Self {
-        workouts.shuffle(&mut rand::rng());
-        workouts = itertools::intersperse(workouts, Workout::rest(rest)).collect_vec();
-        Self {
+        let mut sequence = Self {
             name: name.into(),
             description: description.map(str::to_string),
             workouts: workouts.into(),
             sound: sound.clone(),
             rest: Some(rest),
             shuffleable: true,
+            seed: None,
             difficulty,
             icon,
-        }
+            level: 0,
+        };
+        sequence.shuffle_with_seed(seed.unwrap_or_else(rand::random));
+        sequence
     }
     #[builder]
     pub fn simple(
@@ -102,8 +118,10 @@ impl Sequence {
             sound: sound.clone(),
             rest: None,
             shuffleable: false,
+            seed: None,
             difficulty,
             icon,
+            level: 0,
         }
     }
     #[builder]
@@ -131,8 +149,10 @@ impl Sequence {
             sound: sound.clone(),
             rest: None,
             shuffleable: false,
+            seed: None,
             difficulty,
             icon,
+            level: 0,
         }
     }
     #[builder]
@@ -157,8 +177,116 @@ impl Sequence {
             sound: sound.clone(),
             rest: None,
             shuffleable: false,
+            seed: None,
             difficulty,
             icon,
+            level: 0,
+        }
+    }
+    #[builder]
+    #[allow(clippy::too_many_arguments)]
+    pub fn shadow_boxing(
+        name: &str,
+        description: Option<&str>,
+        difficulty: Difficulty,
+        rounds: Rounds,
+        combos: usize,
+        workout: std::time::Duration,
+        rest: std::time::Duration,
+        seed: u64,
+        sound: &Sound,
+        icon: Option<char>,
+    ) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        #[allow(unstable_name_collisions)]
+        let workouts = (0..rounds)
+            .map(|_| {
+                Combo::randoms(difficulty, combos, &mut rng)
+                    .into_iter()
+                    .map(|combo| combo.item().difficulty(workout, difficulty))
+                    .collect_vec()
+            })
+            .intersperse(vec![Workout::rest(rest)])
+            .flatten()
+            .collect_vec();
+        Self {
+            name: format!("{name} ({}s rest)", rest.as_secs()),
+            description: Some(description.map_or_else(
+                || format!("Random combinations (seed {seed})"),
+                str::to_string,
+            )),
+            workouts: workouts.into(),
+            sound: sound.clone(),
+            rest: None,
+            shuffleable: false,
+            seed: None,
+            difficulty: Some(difficulty),
+            icon,
+            level: 0,
+        }
+    }
+    /// Scale work and rest durations by factors and repeat the whole session `rounds` times
+    #[builder]
+    pub fn scale(
+        &self,
+        #[builder(default = 1.0)] work: f64,
+        #[builder(default = 1.0)] rest: f64,
+        #[builder(default = 1)] rounds: Rounds,
+    ) -> Self {
+        let scaled = |duration: &std::time::Duration, factor: f64| {
+            if duration.is_zero() {
+                return *duration;
+            }
+            std::time::Duration::from_secs(
+                duration.mul_f64(factor.max(0.0)).as_secs_f64().round() as u64
+            )
+            .max(std::time::Duration::from_secs(1))
+        };
+        let between = self
+            .workouts
+            .iter()
+            .find(|workout| workout.is_rest())
+            .map(|workout| *workout.duration())
+            .or(self.rest);
+        let mut workouts = self.workouts.to_vec();
+        for _ in 1..rounds.max(1) {
+            if let Some(between) = between {
+                workouts.push(Workout::rest(between));
+            }
+            workouts.extend(self.workouts.iter().cloned());
+        }
+        let workouts = workouts
+            .into_iter()
+            .map(|workout| {
+                let factor = if workout.is_rest() { rest } else { work };
+                let mut scaled_workout = workout.item().workout(scaled(workout.duration(), factor));
+                scaled_workout.set_difficulty(workout.difficulty());
+                scaled_workout
+            })
+            .collect_vec();
+        let mut sequence = self.clone();
+        sequence.rest = self.rest.map(|duration| scaled(&duration, rest));
+        sequence.workouts = workouts.into();
+        sequence.name = sequence.renamed();
+        sequence
+    }
+    /// Repeat then stretch or shrink the session to get as close as possible to a total duration
+    pub fn fit(&self, target: std::time::Duration) -> Self {
+        let total = self.total().as_secs_f64();
+        if total == 0.0 || target.is_zero() {
+            return self.clone();
+        }
+        let rounds = (target.as_secs_f64() / total).round().max(1.0) as Rounds;
+        let repeated = self.scale().rounds(rounds).call();
+        let factor = target.as_secs_f64() / repeated.total().as_secs_f64();
+        repeated.scale().work(factor).rest(factor).call()
+    }
+    /// Name with a fresh total, replacing a previous "(... total)" suffix
+    fn renamed(&self) -> String {
+        let total = format!("({} total)", self.total().to_string());
+        match self.name.rsplit_once(" (") {
+            Some((name, suffix)) if suffix.ends_with(" total)") => format!("{name} {total}"),
+            _ => format!("{} {total}", self.name),
         }
     }
     pub fn slug(&self) -> String {
@@ -231,6 +359,12 @@ impl Sequence {
     pub fn description(&self) -> &Option<String> {
         &self.description
     }
+    pub fn icon(&self) -> Option<char> {
+        self.icon
+    }
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty
+    }
     pub fn total(&self) -> std::time::Duration {
         std::time::Duration::from_secs(
             self.iter()
@@ -257,6 +391,14 @@ impl Sequence {
                 .sum(),
         )
     }
+    /// Estimated kilocalories for a body weight in kilograms
+    pub fn calories(&self, weight: f64) -> f64 {
+        self.iter().map(|workout| workout.calories(weight)).sum()
+    }
+    /// Training load in MET-minutes
+    pub fn load(&self) -> f64 {
+        self.iter().map(Workout::load).sum()
+    }
     pub fn tags(&self) -> Vec<Tag> {
         self.workouts
             .iter()
@@ -269,21 +411,82 @@ impl Sequence {
         &self.sound
     }
     pub fn shuffle(&mut self) {
-        if self.shuffleable {
-            // if it was interspersed with rest, rebuild sequence
-            if let Some(rest) = self.rest {
-                let rest = Workout::rest(rest);
-                self.workouts.retain(&rest);
-                let workouts = itertools::intersperse(self.workouts.shuffled(), rest).collect_vec();
-                self.workouts = workouts.into();
-            } else {
-                self.workouts.shuffle()
-            }
+        self.shuffle_with_seed(rand::random());
+    }
+    /// Shuffle from a canonical order, the same seed always gives the same sequence
+    pub fn shuffle_with_seed(&mut self, seed: u64) {
+        if !self.shuffleable {
+            return;
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut workouts = self.workouts.clone();
+        // if it was interspersed with rest, rebuild sequence
+        if let Some(rest) = self.rest {
+            workouts.retain(&Workout::rest(rest));
+        }
+        let mut workouts = workouts.to_vec();
+        workouts.sort_by_key(|workout| (workout.item().slug(), *workout.duration()));
+        let mut workouts = IndexedVec::from(workouts).shuffled_with(&mut rng);
+        if let Some(rest) = self.rest {
+            workouts = itertools::intersperse(workouts, Workout::rest(rest)).collect_vec();
         }
+        self.workouts = workouts.into();
+        self.seed = Some(seed);
     }
     pub fn shufflable(&self) -> bool {
         self.shuffleable
     }
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+    /// Name suffix of the progression level, e.g. " (+2)"
+    fn level_suffix(level: i8) -> String {
+        if level == 0 {
+            return String::new();
+        }
+        format!(" ({level:+})")
+    }
+    fn swap_items(&self, step: i8, variants: impl Fn(&Item) -> &[String]) -> Self {
+        let mut sequence = self.clone();
+        sequence.level = self.level.saturating_add(step);
+        sequence.name = format!(
+            "{}{}",
+            self.name.replacen(&Self::level_suffix(self.level), "", 1),
+            Self::level_suffix(sequence.level)
+        );
+        sequence.workouts.apply(|workout| {
+            if let Some(item) = variants(workout.item())
+                .first()
+                .and_then(|slug| ItemList::find(slug))
+            {
+                workout.set_item(item);
+            }
+        });
+        sequence
+    }
+    /// Same session with every exercise swapped for its first easier variant, when it has one
+    #[must_use]
+    pub fn easier(&self) -> Self {
+        self.swap_items(-1, Item::easier)
+    }
+    /// Same session with every exercise swapped for its first harder variant, when it has one
+    #[must_use]
+    pub fn harder(&self) -> Self {
+        self.swap_items(1, Item::harder)
+    }
+    /// Apply `harder` for positive levels and `easier` for negative ones
+    #[must_use]
+    pub fn progress(&self, level: i8) -> Self {
+        let mut sequence = self.clone();
+        for _ in 0..level.unsigned_abs() {
+            sequence = if level > 0 {
+                sequence.harder()
+            } else {
+                sequence.easier()
+            };
+        }
+        sequence
+    }
     pub fn unique_items(&self) -> Vec<&Item> {
         self.workouts
             .iter()
@@ -336,3 +539,145 @@ fn sequence_simple_tests() {
     simple.reset();
     assert_eq!(simple.current(), None);
 }
+
+#[test]
+fn sequence_shadow_boxing_tests() {
+    use time::ext::NumericalStdDuration;
+    let shadow_boxing = |seed| {
+        Sequence::shadow_boxing()
+            .name("shadow")
+            .difficulty(Difficulty::Medium)
+            .rounds(3)
+            .combos(4)
+            .workout(30.std_seconds())
+            .rest(1.std_minutes())
+            .seed(seed)
+            .sound(&Sound::Bell)
+            .call()
+    };
+    let sequence = shadow_boxing(1);
+    assert_eq!(sequence, shadow_boxing(1));
+    assert_ne!(sequence, shadow_boxing(2));
+    assert_eq!(sequence.len(), 3 * 4 + 2);
+    assert_eq!(sequence.rest_total(), 2.std_minutes());
+    assert_eq!(sequence.workout_total(), 6.std_minutes());
+    assert!(
+        sequence
+            .iter()
+            .filter(|workout| !workout.is_rest())
+            .all(|workout| workout
+                .item()
+                .tags()
+                .contains(&crate::tag::Boxing::Punch.into()))
+    );
+}
+
+#[test]
+fn sequence_shuffle_tests() {
+    use crate::item::Item;
+    use time::ext::NumericalStdDuration;
+    let workouts = (0..8)
+        .map(|i| {
+            Item::builder()
+                .name(format!("workout {i}"))
+                .build()
+                .workout(30.std_seconds())
+        })
+        .collect_vec();
+    let random = |seed| {
+        Sequence::random()
+            .name("random")
+            .workouts(workouts.clone())
+            .rest(10.std_seconds())
+            .sound(&Sound::Beep)
+            .seed(seed)
+            .call()
+    };
+    let mut sequence = random(5);
+    assert_eq!(sequence.seed(), Some(5));
+    assert_eq!(sequence, random(5));
+    assert_eq!(sequence.len(), 2 * workouts.len() - 1);
+    for (index, workout) in sequence.iter().enumerate() {
+        assert_eq!(workout.is_rest(), index % 2 == 1);
+    }
+
+    let replayed = sequence.clone();
+    sequence.shuffle_with_seed(6);
+    assert_eq!(sequence.seed(), Some(6));
+    assert_ne!(sequence, replayed);
+    sequence.shuffle_with_seed(5);
+    assert_eq!(sequence, replayed);
+
+    sequence.shuffle();
+    assert_eq!(sequence.len(), replayed.len());
+    assert_eq!(sequence.rest_total(), replayed.rest_total());
+    for (index, workout) in sequence.iter().enumerate() {
+        assert_eq!(workout.is_rest(), index % 2 == 1);
+    }
+    assert_eq!(sequence.unique_items().len(), workouts.len());
+}
+
+#[test]
+fn sequence_progression_tests() {
+    use time::ext::NumericalStdDuration;
+    let sequence = Sequence::simple()
+        .name("legs")
+        .workouts(&[
+            ItemList::Squat.workout(30.std_seconds()),
+            Workout::rest(10.std_seconds()),
+            ItemList::PushUp.workout(30.std_seconds()),
+        ])
+        .sound(&Sound::Silent)
+        .call();
+    let squat: Item = ItemList::Squat.into();
+    let squat_jump: Item = ItemList::SquatJump.into();
+    let burpee_push_up: Item = ItemList::BurpeePushUp.into();
+
+    let harder = sequence.harder();
+    assert_eq!(harder.name(), "legs (1:10 total) (+1)");
+    assert_eq!(harder[0].item(), &squat_jump);
+    assert!(harder[1].is_rest());
+    assert_eq!(harder[2].item(), &burpee_push_up);
+    assert_eq!(harder.total(), sequence.total());
+
+    let easier = sequence.easier();
+    assert_eq!(easier[0].item(), &squat);
+    assert_eq!(harder.easier()[0].item(), &squat);
+    assert_eq!(harder.easier().name(), sequence.name());
+    assert_eq!(sequence.progress(2)[0].item().slug(), "squat-bulgarian");
+    assert_eq!(sequence.progress(2).name(), "legs (1:10 total) (+2)");
+    assert_eq!(sequence.progress(-1), easier);
+    assert_eq!(easier.name(), "legs (1:10 total) (-1)");
+}
+
+#[test]
+fn sequence_scale_tests() {
+    use crate::item_list::ItemList;
+    use time::ext::NumericalStdDuration;
+    let rounds = Sequence::rounds()
+        .name("3x3m")
+        .rounds(3)
+        .workout(ItemList::BoxingRound.workout(3.std_minutes()))
+        .rest(1.std_minutes())
+        .sound(&Sound::Bell)
+        .call();
+    assert_eq!(rounds.total(), 11.std_minutes());
+
+    let longer = rounds.scale().work(1.5).rest(0.5).call();
+    assert_eq!(longer.workout_total(), 810.std_seconds());
+    assert_eq!(longer.rest_total(), 1.std_minutes());
+    assert_eq!(longer.name(), "3x3m (60s rest) (14:30 total)");
+
+    let doubled = rounds.scale().rounds(2).call();
+    assert_eq!(doubled.len(), 2 * rounds.len() + 1);
+    assert_eq!(doubled.total(), 23.std_minutes());
+    assert_eq!(doubled.scale().call().name(), doubled.name());
+
+    let fitted = rounds.fit(25.std_minutes());
+    assert_eq!(fitted.len(), doubled.len());
+    assert!(fitted.total().abs_diff(25.std_minutes()) <= 10.std_seconds());
+    let shorter = rounds.fit(5.std_minutes());
+    assert_eq!(shorter.len(), rounds.len());
+    assert!(shorter.total().abs_diff(5.std_minutes()) <= 5.std_seconds());
+    assert_eq!(rounds.fit(std::time::Duration::ZERO), rounds);
+}
@@ -0,0 +1,310 @@
+use crate::activity::Activity;
+use crate::errors::ErrorKind;
+use crate::heart_rate::HeartRate;
+use crate::sequence::Sequence;
+use crate::summary::Run;
+use crate::workout::Workout;
+use bon::Builder;
+use derive_more::{Deref, DerefMut, From, IntoIterator};
+use serde::{Deserialize, Serialize};
+
+/// One workout interval of a session
+#[derive(Clone, Debug, PartialEq, Builder, Serialize, Deserialize)]
+pub struct Lap {
+    #[builder(into)]
+    name: String,
+    activity: Activity,
+    duration: std::time::Duration,
+    calories: f64,
+    /// Unix timestamp in seconds, missing from laps recorded back to back
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    started: Option<i64>,
+}
+
+impl Lap {
+    pub fn record(workout: &Workout, weight: f64) -> Self {
+        Self::builder()
+            .name(workout.item().name())
+            .activity(workout.item().activity())
+            .duration(*workout.duration())
+            .calories(workout.calories(weight))
+            .build()
+    }
+    /// Part of a workout, counted down for `duration` from `started`
+    pub fn partial(
+        workout: &Workout,
+        duration: std::time::Duration,
+        weight: f64,
+        started: i64,
+    ) -> Self {
+        Self::builder()
+            .name(workout.item().name())
+            .activity(workout.item().activity())
+            .duration(duration)
+            .calories(workout.met().calories(weight, duration))
+            .started(started)
+            .build()
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn activity(&self) -> Activity {
+        self.activity
+    }
+    pub fn duration(&self) -> std::time::Duration {
+        self.duration
+    }
+    pub fn calories(&self) -> f64 {
+        self.calories
+    }
+    pub fn is_rest(&self) -> bool {
+        self.activity == Activity::Rest
+    }
+}
+
+/// A completed sequence with its estimated effort
+#[derive(Clone, Debug, PartialEq, Builder, Serialize, Deserialize)]
+pub struct Session {
+    #[builder(into)]
+    name: String,
+    #[builder(into)]
+    slug: String,
+    /// Unix timestamp in seconds
+    started: i64,
+    duration: std::time::Duration,
+    /// Estimated kilocalories
+    calories: f64,
+    /// Training load in MET-minutes
+    load: f64,
+    /// Missing from sessions recorded before laps existed
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    laps: Vec<Lap>,
+    /// Imported from a heart rate strap, limited to the session time
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "HeartRate::is_empty")]
+    heart_rate: HeartRate,
+    /// Rating of perceived exertion, from 1 to 10
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rpe: Option<u8>,
+}
+
+impl Session {
+    /// Record a sequence completed by someone of the given body weight in kilograms
+    pub fn record(sequence: &Sequence, weight: f64, started: i64) -> Self {
+        Self::builder()
+            .name(sequence.name())
+            .slug(sequence.slug())
+            .started(started)
+            .duration(sequence.total())
+            .calories(sequence.calories(weight))
+            .load(sequence.load())
+            .laps(
+                sequence
+                    .iter()
+                    .map(|workout| Lap::record(workout, weight))
+                    .collect(),
+            )
+            .build()
+    }
+    /// Record the runs of a timer, skipped workouts are left out and cut ones shortened
+    pub fn run(sequence: &Sequence, runs: &[Run], weight: f64, started: i64) -> Self {
+        let ran = runs
+            .iter()
+            .filter(|run| !run.duration.is_zero())
+            .filter_map(|run| {
+                let start = started.saturating_add_unsigned(run.offset.as_secs());
+                Some((sequence.get(run.index)?, run.duration, start))
+            })
+            .collect::<Vec<_>>();
+        Self::builder()
+            .name(sequence.name())
+            .slug(sequence.slug())
+            .started(started)
+            .duration(ran.iter().map(|(_, duration, _)| *duration).sum())
+            .calories(
+                ran.iter()
+                    .map(|(workout, duration, _)| workout.met().calories(weight, *duration))
+                    .sum(),
+            )
+            .load(
+                ran.iter()
+                    .map(|(workout, duration, _)| workout.met().load(*duration))
+                    .sum(),
+            )
+            .laps(
+                ran.iter()
+                    .map(|(workout, duration, start)| {
+                        Lap::partial(workout, *duration, weight, *start)
+                    })
+                    .collect(),
+            )
+            .build()
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+    pub fn started(&self) -> i64 {
+        self.started
+    }
+    pub fn duration(&self) -> std::time::Duration {
+        self.duration
+    }
+    pub fn calories(&self) -> f64 {
+        self.calories
+    }
+    pub fn load(&self) -> f64 {
+        self.load
+    }
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+    pub fn heart_rate(&self) -> &HeartRate {
+        &self.heart_rate
+    }
+    /// Keep the readings taken during the session, a file of another day is refused
+    pub fn set_heart_rate(&mut self, heart_rate: &HeartRate) -> Result<(), ErrorKind> {
+        let during = heart_rate.during(self);
+        if during.is_empty() {
+            return Err(ErrorKind::HeartRateOutsideSession);
+        }
+        self.heart_rate = during;
+        Ok(())
+    }
+    pub fn rpe(&self) -> Option<u8> {
+        self.rpe
+    }
+    pub fn set_rpe(&mut self, rpe: u8) {
+        self.rpe = Some(rpe.clamp(1, 10));
+    }
+    /// Unix time at the end of the last lap, pauses included
+    pub fn ended(&self) -> i64 {
+        self.intervals()
+            .iter()
+            .map(|(start, lap)| start.saturating_add_unsigned(lap.duration.as_secs()))
+            .max()
+            .unwrap_or(self.started)
+    }
+    /// Laps with their unix start time, laps without one follow the previous lap,
+    /// sessions recorded before laps existed are a single lap
+    pub fn intervals(&self) -> Vec<(i64, Lap)> {
+        if self.laps.is_empty() {
+            let whole = Lap::builder()
+                .name(self.name.clone())
+                .activity(Activity::default())
+                .duration(self.duration)
+                .calories(self.calories)
+                .build();
+            return vec![(self.started, whole)];
+        }
+        self.laps
+            .iter()
+            .scan(self.started, |start, lap| {
+                let current = lap.started.unwrap_or(*start);
+                *start = current.saturating_add_unsigned(lap.duration.as_secs());
+                Some((current, lap.clone()))
+            })
+            .collect()
+    }
+    /// The activity holding the most time, rest aside
+    pub fn activity(&self) -> Activity {
+        let mut durations: Vec<(Activity, std::time::Duration)> = Vec::new();
+        for lap in self.laps.iter().filter(|lap| !lap.is_rest()) {
+            match durations
+                .iter_mut()
+                .find(|(activity, _)| *activity == lap.activity)
+            {
+                Some((_, duration)) => *duration += lap.duration,
+                None => durations.push((lap.activity, lap.duration)),
+            }
+        }
+        durations
+            .into_iter()
+            .max_by_key(|(_, duration)| *duration)
+            .map(|(activity, _)| activity)
+            .unwrap_or_default()
+    }
+}
+
+#[derive(
+    Default, Clone, Debug, PartialEq, Deref, DerefMut, IntoIterator, From, Serialize, Deserialize,
+)]
+pub struct History(Vec<Session>);
+
+impl History {
+    /// Sessions started at or after a unix timestamp
+    pub fn since(&self, timestamp: i64) -> Self {
+        self.iter()
+            .filter(|session| session.started >= timestamp)
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+    pub fn duration(&self) -> std::time::Duration {
+        self.iter().map(Session::duration).sum()
+    }
+    pub fn calories(&self) -> f64 {
+        self.iter().map(Session::calories).sum()
+    }
+    pub fn load(&self) -> f64 {
+        self.iter().map(Session::load).sum()
+    }
+}
+
+#[test]
+fn session_tests() {
+    use crate::defaults::SEQUENCES;
+
+    let sequence = SEQUENCES.first().unwrap();
+    let session = Session::record(sequence, 70.0, 1000);
+    assert_eq!(session.slug(), sequence.slug());
+    assert_eq!(session.duration(), sequence.total());
+    assert!(session.calories() > 0.0);
+    assert!(session.load() > 0.0);
+    assert_eq!(session.laps().len(), sequence.len());
+    assert_eq!(
+        session
+            .laps()
+            .iter()
+            .map(Lap::duration)
+            .sum::<std::time::Duration>(),
+        session.duration()
+    );
+    assert!(
+        (session.laps().iter().map(Lap::calories).sum::<f64>() - session.calories()).abs() < 1e-9
+    );
+
+    let heavier = Session::record(sequence, 90.0, 2000);
+    assert!(heavier.calories() > session.calories());
+    assert!((heavier.load() - session.load()).abs() < 1e-9);
+
+    let mut rated = heavier.clone();
+    rated.set_rpe(12);
+    assert_eq!(rated.rpe(), Some(10));
+    assert_eq!(heavier.rpe(), None);
+
+    let history = History::from(vec![session.clone(), rated]);
+    assert_eq!(history.duration(), sequence.total() * 2);
+    assert_eq!(history.since(1500).len(), 1);
+    assert!((history.since(0).load() - 2.0 * session.load()).abs() < 1e-9);
+
+    let json = serde_json::to_string(&history).unwrap();
+    assert_eq!(serde_json::from_str::<History>(&json).unwrap(), history);
+
+    let legacy = r#"{"name":"Old","slug":"old","started":0,"duration":{"secs":60,"nanos":0},"calories":5.0,"load":3.0}"#;
+    let legacy = serde_json::from_str::<Session>(legacy).unwrap();
+    assert!(legacy.laps().is_empty());
+    assert_eq!(legacy.activity(), Activity::Training);
+    assert_eq!(legacy.intervals().len(), 1);
+
+    let intervals = session.intervals();
+    assert_eq!(intervals[0].0, 1000);
+    let (last_start, last) = intervals.last().unwrap();
+    assert_eq!(
+        last_start.saturating_add_unsigned(last.duration().as_secs()),
+        1000_i64.saturating_add_unsigned(session.duration().as_secs())
+    );
+}
@@ -1,3 +1,4 @@
+use crate::i18n::{Locale, Text};
 use crate::player::Player;
 use crate::sound::Sound;
 use derive_more::Display;
@@ -25,9 +26,13 @@ impl SoundState {
     }
     #[must_use]
     pub fn next_title(&self) -> String {
+        Locale::En.text(self.next_text()).to_string()
+    }
+    #[must_use]
+    pub fn next_text(&self) -> Text {
         match self.next() {
-            Self::Disabled => "Disable sound".to_string(),
-            Self::Enabled => "Enable sound".to_string(),
+            Self::Disabled => Text::DisableSound,
+            Self::Enabled => Text::EnableSound,
         }
     }
     #[must_use]
@@ -64,9 +69,9 @@ impl SoundSignal {
         self.state.borrow_mut().toggle();
     }
     pub fn ring(&self, sound: &Sound, player: &dyn Player) {
-        if self.enabled() {
-            if let Err(_error) = player.play(sound) {}
-        }
+        if self.enabled()
+            && let Err(_error) = player.play(sound)
+        {}
     }
     pub fn always_ring(&self, sound: &Sound, player: &dyn Player) {
         if let Err(_error) = player.play(sound) {}
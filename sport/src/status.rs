@@ -1,19 +1,15 @@
+use crate::i18n::{Locale, Text};
 use derive_more::Display;
 
-#[derive(Display, Debug, PartialEq, Eq, Clone)]
+#[derive(Display, Debug, Default, PartialEq, Eq, Clone)]
 pub enum Status {
+    #[default]
     #[display("🛑")]
     Paused,
     #[display("🟢")]
     Running,
 }
 
-impl Default for Status {
-    fn default() -> Self {
-        Self::Paused
-    }
-}
-
 impl Status {
     pub fn toggle(&mut self) -> &mut Self {
         *self = self.next().clone();
@@ -28,9 +24,13 @@ impl Status {
     }
     #[must_use]
     pub fn next_title(&self) -> String {
+        Locale::En.text(self.next_text()).to_string()
+    }
+    #[must_use]
+    pub fn next_text(&self) -> Text {
         match self.next() {
-            Self::Paused => "Pause timer".to_string(),
-            Self::Running => "Start timer".to_string(),
+            Self::Paused => Text::PauseTimer,
+            Self::Running => Text::StartTimer,
         }
     }
     #[must_use]
@@ -31,6 +31,12 @@ impl Stopwatch {
         }
         success
     }
+    /// Jump to a remaining time, never above the duration
+    pub fn set_left(&mut self, left: std::time::Duration) -> &mut Self {
+        self.left = left.min(self.duration);
+        self.elapsed = self.duration - self.left;
+        self
+    }
     pub fn last_seconds(&self) -> bool {
         self.left < 3.std_seconds()
     }
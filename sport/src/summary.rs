@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+/// What actually happened while a timer ran, next to what the sequence planned
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub planned_work: Duration,
+    pub planned_rest: Duration,
+    /// Counted down while running
+    pub work: Duration,
+    pub rest: Duration,
+    pub preparation: Duration,
+    /// Paused once started
+    pub paused: Duration,
+    pub pauses: usize,
+    /// Names of the workouts left before their end
+    pub skipped: Vec<String>,
+    /// Workouts in the order they counted down
+    pub runs: Vec<Run>,
+}
+
+/// Time counted down on one workout of the sequence
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run {
+    pub index: usize,
+    /// Wall time spent before the run, preparation and pauses included
+    pub offset: Duration,
+    pub duration: Duration,
+}
+
+impl Summary {
+    /// Wall time from the first second to the end, pauses included
+    pub fn spent(&self) -> Duration {
+        self.work + self.rest + self.preparation + self.paused
+    }
+    pub fn started(&self) -> bool {
+        !(self.work + self.rest + self.preparation).is_zero()
+    }
+    /// At least half of the planned work was done
+    pub fn done(&self) -> bool {
+        self.work * 2 >= self.planned_work
+    }
+    /// Count time on the workout at `index`, a new run when the workout changed or was paused
+    pub fn ran(&mut self, index: usize, duration: Duration) {
+        let spent = self.spent();
+        match self.runs.last_mut() {
+            Some(run) if run.index == index && run.offset + run.duration == spent => {
+                run.duration += duration;
+            }
+            _ => self.runs.push(Run {
+                index,
+                offset: spent,
+                duration,
+            }),
+        }
+    }
+}
@@ -67,7 +67,20 @@ impl Tag {
 // #[serde(transparent)]
 pub struct Tags(Vec<Tag>);
 
-#[derive(Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(
+    Display,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    VariantArray,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum Difficulty {
     Easy,
@@ -85,6 +98,15 @@ impl Difficulty {
             Self::Elite => '🟥',
         }
     }
+    /// Effort multiplier applied to the base MET of an item
+    pub fn intensity(&self) -> f64 {
+        match self {
+            Self::Easy => 0.8,
+            Self::Medium => 1.0,
+            Self::Hard => 1.2,
+            Self::Elite => 1.4,
+        }
+    }
 }
 
 #[derive(Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -94,6 +116,7 @@ pub enum Boxing {
     Punch,
     Roll,
     Slip,
+    Pull,
 }
 
 #[derive(Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -117,7 +140,9 @@ pub enum Squat {
     Bulgarian,
 }
 
-#[derive(Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(
+    Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, VariantArray,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum Equipment {
     None,
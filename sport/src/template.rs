@@ -0,0 +1,221 @@
+use crate::duration::DurationExt;
+use crate::item::Item;
+use crate::sequence::Sequence;
+use crate::sound::Sound;
+use crate::tag::Difficulty;
+use bon::Builder;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use slug::slugify;
+use std::collections::HashMap;
+use strum_macros::VariantArray;
+
+#[derive(
+    Display, Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize, VariantArray,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ParameterKind {
+    /// Number of work intervals
+    Rounds,
+    /// Work interval, in seconds
+    Work,
+    /// Rest between work intervals, in seconds
+    Rest,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Builder, Serialize, Deserialize)]
+pub struct Parameter {
+    kind: ParameterKind,
+    min: u64,
+    max: u64,
+    default: u64,
+    #[builder(default = 1)]
+    step: u64,
+}
+
+impl Parameter {
+    /// A parameter that cannot be changed
+    pub fn fixed(kind: ParameterKind, value: u64) -> Self {
+        Self::builder()
+            .kind(kind)
+            .min(value)
+            .max(value)
+            .default(value)
+            .build()
+    }
+    pub fn kind(&self) -> ParameterKind {
+        self.kind
+    }
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+    pub fn max(&self) -> u64 {
+        self.max
+    }
+    pub fn default(&self) -> u64 {
+        self.default
+    }
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+    pub fn is_fixed(&self) -> bool {
+        self.min == self.max
+    }
+    /// Value kept in range and snapped to the step, zero stands for the default
+    pub fn value(&self, value: u64) -> u64 {
+        if value == 0 {
+            return self.default;
+        }
+        let value = value.clamp(self.min, self.max);
+        let step = self.step.max(1);
+        self.min + (value - self.min) / step * step
+    }
+    pub fn format(&self, value: u64) -> String {
+        match self.kind {
+            ParameterKind::Rounds => value.to_string(),
+            ParameterKind::Work | ParameterKind::Rest => {
+                std::time::Duration::from_secs(value).to_string()
+            }
+        }
+    }
+}
+
+pub type Values = HashMap<ParameterKind, u64>;
+
+/// A family of round based sequences, instantiated on demand from parameter values
+#[derive(Clone, Debug, PartialEq, Eq, Builder, Serialize, Deserialize)]
+pub struct Template {
+    #[builder(into)]
+    name: String,
+    #[builder(into)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    item: Item,
+    rounds: Parameter,
+    work: Parameter,
+    rest: Parameter,
+    sound: Sound,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    difficulty: Option<Difficulty>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    icon: Option<char>,
+}
+
+impl std::fmt::Display for Template {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}{}", self.icon.unwrap_or('‎'), self.name)
+    }
+}
+
+impl Template {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn slug(&self) -> String {
+        slugify(&self.name)
+    }
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+    pub fn parameters(&self) -> [&Parameter; 3] {
+        [&self.rounds, &self.work, &self.rest]
+    }
+    pub fn parameter(&self, kind: ParameterKind) -> &Parameter {
+        match kind {
+            ParameterKind::Rounds => &self.rounds,
+            ParameterKind::Work => &self.work,
+            ParameterKind::Rest => &self.rest,
+        }
+    }
+    pub fn defaults(&self) -> Values {
+        self.parameters()
+            .iter()
+            .map(|parameter| (parameter.kind(), parameter.default()))
+            .collect()
+    }
+    /// Build the sequence, missing or out of range values fall back into the parameter ranges
+    pub fn instantiate(&self, values: &Values) -> Sequence {
+        let value = |kind| {
+            self.parameter(kind)
+                .value(values.get(&kind).copied().unwrap_or_default())
+        };
+        let (rounds, work, rest) = (
+            value(ParameterKind::Rounds),
+            value(ParameterKind::Work),
+            value(ParameterKind::Rest),
+        );
+        let workout = self.item.workout(std::time::Duration::from_secs(work));
+        Sequence::rounds()
+            .name(&format!(
+                "{} {rounds}x{}",
+                self.name,
+                self.work.format(work)
+            ))
+            .maybe_description(self.description.as_deref())
+            .rounds(usize::try_from(rounds).unwrap_or(usize::MAX))
+            .workout(workout)
+            .rest(std::time::Duration::from_secs(rest))
+            .sound(&self.sound)
+            .maybe_difficulty(self.difficulty)
+            .maybe_icon(self.icon)
+            .call()
+    }
+}
+
+#[test]
+fn template_tests() {
+    use crate::item_list::ItemList;
+    let template = Template::builder()
+        .name("Boxing rounds")
+        .item(ItemList::BoxingRound.into())
+        .rounds(
+            Parameter::builder()
+                .kind(ParameterKind::Rounds)
+                .min(1)
+                .max(12)
+                .default(3)
+                .build(),
+        )
+        .work(
+            Parameter::builder()
+                .kind(ParameterKind::Work)
+                .min(60)
+                .max(300)
+                .default(180)
+                .step(30)
+                .build(),
+        )
+        .rest(Parameter::fixed(ParameterKind::Rest, 60))
+        .sound(Sound::Bell)
+        .icon('🥊')
+        .build();
+    assert_eq!(template.slug(), "boxing-rounds");
+
+    let sequence = template.instantiate(&Values::new());
+    assert_eq!(sequence.name(), "Boxing rounds 3x3:00 (60s rest)");
+    assert_eq!(sequence.len(), 5);
+    assert_eq!(sequence.total(), std::time::Duration::from_secs(11 * 60));
+    assert_eq!(sequence, template.instantiate(&template.defaults()));
+
+    let values = Values::from([
+        (ParameterKind::Rounds, 20),
+        (ParameterKind::Work, 100),
+        (ParameterKind::Rest, 10),
+    ]);
+    let sequence = template.instantiate(&values);
+    assert_eq!(sequence.name(), "Boxing rounds 12x1:30 (60s rest)");
+    assert_eq!(
+        sequence.workout_total(),
+        std::time::Duration::from_secs(12 * 90)
+    );
+    assert_eq!(
+        sequence.rest_total(),
+        std::time::Duration::from_secs(11 * 60)
+    );
+
+    let single = template.instantiate(&Values::from([(ParameterKind::Rounds, 1)]));
+    assert_eq!(single.name(), "Boxing rounds 1x3:00 (60s rest)");
+    assert_eq!(single.len(), 1);
+    assert_eq!(single.total(), std::time::Duration::from_secs(3 * 60));
+    assert_eq!(single.icon(), Some('🥊'));
+}
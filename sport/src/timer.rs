@@ -1,18 +1,60 @@
 use crate::defaults;
+use crate::i18n::{Locale, Text};
 use crate::player::Player;
+use crate::protocol::TimerState;
 use crate::sequence::Sequence;
+use crate::session::Session;
 use crate::signal::SoundSignal;
 use crate::status::Status;
 use crate::stopwatch::Stopwatch;
+use crate::summary::Summary;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+const SECOND: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Where a timer stands in its sequence, paused or running alike
+///
+/// - `Preparing` counts down before the first workout, ticks then start it
+/// - `Working` and `Resting` follow the current workout, on ticks and manual navigation
+/// - `Finished` once the last workout runs down or is skipped, paused until restarted or navigated
+#[derive(Display, Default, Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    #[default]
+    Preparing,
+    Working,
+    Resting,
+    Finished,
+}
+
+impl Phase {
+    /// Phase of the current workout, preparing before the first one
+    fn of(sequence: &Sequence) -> Self {
+        match sequence.current() {
+            None => Self::Preparing,
+            Some(workout) if workout.is_rest() => Self::Resting,
+            Some(_) => Self::Working,
+        }
+    }
+    #[must_use]
+    pub fn is_finished(self) -> bool {
+        self == Self::Finished
+    }
+}
 
 #[derive(Debug)]
 pub struct Timer {
     status: Status,
+    phase: Phase,
     sequence: Sequence,
     preparation: Stopwatch,
     changed: bool,
     sound_signal: SoundSignal,
     player: Box<dyn Player>,
+    summary: Summary,
+    /// Finished and not yet taken by `session`
+    unrecorded: bool,
 }
 
 impl Timer {
@@ -37,7 +79,10 @@ impl Timer {
             changed: false,
             sound_signal: sound_signal.clone(),
             status: Status::default(),
+            phase: Phase::default(),
             player,
+            summary: Summary::default(),
+            unrecorded: false,
         }
     }
     #[must_use]
@@ -58,25 +103,47 @@ impl Timer {
     pub fn ring(&self) {
         self.sound_signal.ring(self.sequence.sound(), &*self.player);
     }
+    /// Back to `Preparing`, a running timer keeps running
     pub fn restart_sequence(&mut self) {
         self.preparation.reset();
         self.changed = true;
         self.sequence.reset();
+        self.start_over();
+        self.enter();
+    }
+    /// A new run, counted apart from the previous one
+    fn start_over(&mut self) {
+        self.summary = Summary::default();
+        self.unrecorded = false;
     }
+    /// A finished timer is back on its last workout, still paused
     pub fn restart_workout(&mut self) {
         self.preparation.reset();
         self.sequence.reset_workout();
+        self.enter();
     }
+    /// Follow the current workout
+    fn enter(&mut self) {
+        self.phase = Phase::of(&self.sequence);
+    }
+    /// Count down one second, true when the next workout just started
     pub fn tick(&mut self) -> bool {
         if self.changed {
             self.changed = false;
             return false;
         }
+        if self.phase.is_finished() {
+            return false;
+        }
         if self.status.paused() {
+            if self.summary.started() {
+                self.summary.paused += SECOND;
+            }
             return false;
         }
 
         if self.sequence.current().is_none() && self.preparation.decrement() {
+            self.summary.preparation += SECOND;
             if self.sequence.sound().is_beep() && self.preparation.last_seconds() {
                 self.sound_signal.ring(self.sequence.sound(), &*self.player);
             }
@@ -85,6 +152,14 @@ impl Timer {
         self.preparation.reset();
 
         if self.sequence.decrement() {
+            if let Some(index) = self.sequence.index() {
+                self.summary.ran(index, SECOND);
+            }
+            if self.phase == Phase::Resting {
+                self.summary.rest += SECOND;
+            } else {
+                self.summary.work += SECOND;
+            }
             if self.sequence.sound().is_beep() && self.sequence.last_seconds() {
                 self.sound_signal.ring(self.sequence.sound(), &*self.player);
             }
@@ -95,22 +170,68 @@ impl Timer {
             self.sound_signal.ring(self.sequence.sound(), &*self.player);
         }
         if self.sequence.auto_next().is_some() {
+            self.enter();
             return true;
         }
-        self.status.toggle();
+        if self.phase != Phase::Preparing {
+            self.finish();
+        }
         false
     }
+    /// A finished timer stops running until restarted
+    fn finish(&mut self) {
+        self.status = Status::Paused;
+        self.phase = Phase::Finished;
+        self.unrecorded = true;
+    }
+    /// Session of a timer that just finished, on ticks or skips alike, taken once,
+    /// with only the time that actually ran
+    pub fn session(&mut self, weight: f64, now: i64) -> Option<Session> {
+        if !std::mem::take(&mut self.unrecorded) {
+            return None;
+        }
+        let started = now.saturating_sub_unsigned(self.summary.spent().as_secs());
+        Some(Session::run(
+            &self.sequence,
+            &self.summary.runs,
+            weight,
+            started,
+        ))
+    }
+    /// Skip to the next workout, skipping the last one finishes and a finished timer starts over
     pub fn manual_next(&mut self) {
         if self.status().running() {
             self.changed = true;
         }
+        if self.phase.is_finished() {
+            self.start_over();
+        }
+        if !self.phase.is_finished()
+            && let Some(workout) = self.sequence.current()
+            && !workout.left().is_zero()
+        {
+            self.summary.skipped.push(workout.item().name().to_string());
+        }
+        if !self.phase.is_finished()
+            && self.sequence.last()
+            && let Some(workout) = self.sequence.current_mut()
+        {
+            workout.set_left(std::time::Duration::ZERO);
+            self.finish();
+            return;
+        }
         self.sequence.manual_next();
+        self.enter();
     }
     pub fn manual_previous(&mut self) {
         if self.status().running() {
             self.changed = true;
         }
+        if self.phase.is_finished() {
+            self.start_over();
+        }
         self.sequence.goto_previous();
+        self.enter();
     }
     #[must_use]
     pub fn label(&self) -> &str {
@@ -122,17 +243,302 @@ impl Timer {
         };
         workout.item().name()
     }
+    /// The last workout ran down to zero or was skipped
+    #[must_use]
+    pub fn completed(&self) -> bool {
+        self.phase.is_finished()
+    }
+    /// Translated `label`
+    #[must_use]
+    pub fn localized_label(&self, locale: Locale) -> String {
+        match self.sequence.current() {
+            Some(workout) => locale.item_name(workout.item()),
+            None => locale.text(Text::Prepare).to_string(),
+        }
+    }
     #[must_use]
     pub fn status(&self) -> &Status {
         &self.status
     }
+    #[must_use]
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+    /// Time spent so far against the planned totals
+    #[must_use]
+    pub fn summary(&self) -> Summary {
+        Summary {
+            planned_work: self.sequence.workout_total(),
+            planned_rest: self.sequence.rest_total(),
+            ..self.summary.clone()
+        }
+    }
+    /// Snapshot to mirror this timer on another device
+    #[must_use]
+    pub fn state(&self) -> TimerState {
+        TimerState {
+            sequence: self.sequence.clone(),
+            index: self.sequence.index(),
+            left: *self.left(),
+            running: self.status.running(),
+            phase: self.phase,
+        }
+    }
+    /// Mirror a remote timer, a follower does not tick by itself
+    pub fn follow(&mut self, state: &TimerState) {
+        self.sequence = state.sequence.clone();
+        self.sequence.reset();
+        self.preparation.reset();
+        match state.index {
+            Some(index) => {
+                if let Some(workout) = self.sequence.set_index(index) {
+                    workout.set_left(state.left);
+                }
+            }
+            None => {
+                self.preparation.set_left(state.left);
+            }
+        }
+        self.status = if state.running {
+            Status::Running
+        } else {
+            Status::Paused
+        };
+        self.phase = if state.phase.is_finished() {
+            Phase::Finished
+        } else {
+            Phase::of(&self.sequence)
+        };
+    }
     pub fn shuffle(&mut self) {
         self.sequence.shuffle();
     }
+    /// Pause or resume, a finished timer starts over
     pub fn toggle(&mut self) {
+        if self.phase.is_finished() {
+            self.restart_sequence();
+        }
+        if self.status.running() {
+            self.summary.pauses += 1;
+        }
         self.status = self.status().next().clone();
         if self.status().running() {
             self.changed = true;
         }
     }
 }
+
+#[test]
+fn timer_finished_tests() {
+    use crate::item_list::ItemList;
+    use crate::player::Silent;
+    use crate::sound::Sound;
+    use crate::workout::Workout;
+
+    let seconds = std::time::Duration::from_secs;
+    let sequence = Sequence::builder()
+        .name("short")
+        .sound(Sound::default())
+        .workouts(vec![
+            ItemList::JumpingJack.workout(seconds(3)),
+            Workout::rest(seconds(2)),
+            ItemList::Burpee.workout(seconds(4)),
+        ])
+        .build();
+    let signal = SoundSignal::from_muted(true);
+    let mut timer = Timer::new(seconds(2), &sequence, &signal, Box::new(Silent));
+    timer.toggle();
+    // the tick right after a start is swallowed
+    for _ in 0..12 {
+        timer.tick();
+    }
+    timer.toggle();
+    for _ in 0..5 {
+        timer.tick();
+    }
+    timer.toggle();
+    timer.manual_next();
+    for _ in 0..10 {
+        timer.tick();
+    }
+    assert_eq!(timer.phase(), Phase::Finished);
+    assert!(timer.completed());
+    let session = timer.session(70.0, 1_000).unwrap();
+    assert_eq!(session.slug(), "short");
+    assert!(timer.session(70.0, 1_000).is_none());
+    let summary = timer.summary();
+    assert_eq!(summary.planned_work, seconds(7));
+    assert_eq!(summary.planned_rest, seconds(2));
+    assert_eq!(summary.preparation, seconds(2));
+    assert_eq!(summary.rest, seconds(2));
+    assert_eq!(summary.pauses, 1);
+    assert_eq!(summary.paused, seconds(5));
+    assert!(summary.work < seconds(7));
+    assert_eq!(summary.skipped.len(), 1);
+    assert_eq!(
+        summary.spent(),
+        summary.work + summary.rest + seconds(2) + seconds(5)
+    );
+
+    // a finished timer stays still until restarted
+    let left = *timer.left();
+    timer.tick();
+    assert_eq!(*timer.left(), left);
+    timer.toggle();
+    assert!(timer.status().running());
+    assert_eq!(timer.summary().spent(), std::time::Duration::ZERO);
+    assert!(!timer.completed());
+    assert!(timer.session(70.0, 1_000).is_none());
+
+    // skipping the last workout records the session as well
+    for _ in 0..4 {
+        timer.manual_next();
+    }
+    assert!(timer.completed());
+    let session = timer.session(70.0, 1_000).unwrap();
+    assert_eq!(session.slug(), "short");
+    assert!(timer.session(70.0, 1_000).is_none());
+}
+
+#[test]
+fn timer_session_tests() {
+    use crate::item_list::ItemList;
+    use crate::player::Silent;
+    use crate::sound::Sound;
+    use crate::workout::Workout;
+
+    let seconds = std::time::Duration::from_secs;
+    let sequence = Sequence::builder()
+        .name("skipped")
+        .sound(Sound::default())
+        .workouts(vec![
+            ItemList::JumpingJack.workout(seconds(30)),
+            Workout::rest(seconds(10)),
+            ItemList::Burpee.workout(seconds(30)),
+            Workout::rest(seconds(10)),
+            ItemList::Squat.workout(seconds(30)),
+        ])
+        .build();
+    let signal = SoundSignal::from_muted(true);
+    let mut timer = Timer::new(seconds(2), &sequence, &signal, Box::new(Silent));
+    timer.toggle();
+    // skipped during the preparation, then 5 seconds of burpees before skipping the rest
+    timer.manual_next();
+    timer.manual_next();
+    timer.manual_next();
+    for _ in 0..6 {
+        timer.tick();
+    }
+    timer.manual_next();
+    timer.manual_next();
+    timer.manual_next();
+    assert!(timer.completed());
+    assert!(!timer.summary().done());
+
+    let session = timer.session(70.0, 1_000).unwrap();
+    assert_eq!(session.duration(), seconds(5));
+    assert_eq!(session.laps().len(), 1);
+    assert_eq!(session.laps()[0].name(), "Burpee");
+    let full = Session::record(&sequence, 70.0, 1_000);
+    assert!(session.calories() < full.calories() / 10.0);
+    assert!(session.load() < full.load() / 10.0);
+
+    // navigating out of a finished timer starts a new run
+    timer.manual_next();
+    assert_eq!(timer.summary().skipped, Vec::<String>::new());
+    assert!(timer.session(70.0, 2_000).is_none());
+    timer.toggle();
+    for _ in 0..4 {
+        timer.tick();
+    }
+    for _ in 0..5 {
+        timer.manual_next();
+    }
+    assert!(timer.completed());
+    let summary = timer.summary();
+    assert_eq!(summary.skipped.len(), 5);
+    let session = timer.session(70.0, 2_000).unwrap();
+    assert_eq!(session.duration(), summary.work + summary.rest);
+    assert_eq!(
+        session.started(),
+        2_000 - i64::try_from(summary.spent().as_secs()).unwrap()
+    );
+    assert!(session.duration() < seconds(5));
+}
+
+#[test]
+fn timer_phase_tests() {
+    use crate::item_list::ItemList;
+    use crate::player::Silent;
+    use crate::sound::Sound;
+    use crate::workout::Workout;
+
+    let seconds = std::time::Duration::from_secs;
+    let sequence = Sequence::builder()
+        .name("phases")
+        .sound(Sound::default())
+        .workouts(vec![
+            ItemList::JumpingJack.workout(seconds(2)),
+            Workout::rest(seconds(2)),
+        ])
+        .build();
+    let signal = SoundSignal::from_muted(true);
+    let mut timer = Timer::new(seconds(3), &sequence, &signal, Box::new(Silent));
+    let ticks = |timer: &mut Timer, count| {
+        for _ in 0..count {
+            timer.tick();
+        }
+    };
+    assert_eq!(timer.phase(), Phase::Preparing);
+
+    // pausing during the preparation holds it
+    timer.toggle();
+    ticks(&mut timer, 2);
+    timer.toggle();
+    ticks(&mut timer, 5);
+    assert_eq!(timer.phase(), Phase::Preparing);
+    assert_eq!(*timer.left(), seconds(2));
+
+    timer.toggle();
+    ticks(&mut timer, 4);
+    assert_eq!(timer.phase(), Phase::Working);
+    ticks(&mut timer, 3);
+    assert_eq!(timer.phase(), Phase::Resting);
+    assert_eq!(
+        timer.label(),
+        ItemList::Rest.workout(seconds(1)).item().name()
+    );
+
+    timer.manual_previous();
+    assert_eq!(timer.phase(), Phase::Working);
+    assert!(timer.status().running());
+
+    // skipping the last workout finishes, ticks then do nothing
+    timer.manual_next();
+    timer.manual_next();
+    assert_eq!(timer.phase(), Phase::Finished);
+    ticks(&mut timer, 3);
+    assert_eq!(timer.phase(), Phase::Finished);
+    assert!(timer.status().paused());
+
+    // navigating out of a finished timer pauses it on the workout
+    timer.manual_previous();
+    assert_eq!(timer.phase(), Phase::Working);
+    assert!(timer.status().paused());
+
+    timer.restart_sequence();
+    assert_eq!(timer.phase(), Phase::Preparing);
+    timer.manual_next();
+    assert_eq!(timer.phase(), Phase::Working);
+    assert_eq!(timer.summary().skipped, Vec::<String>::new());
+
+    let mut follower = Timer::new(seconds(3), &Sequence::default(), &signal, Box::new(Silent));
+    timer.manual_next();
+    timer.manual_next();
+    follower.follow(&timer.state());
+    assert_eq!(follower.phase(), Phase::Finished);
+    assert_eq!(
+        serde_json::to_string(&Phase::Resting).unwrap(),
+        r#""resting""#
+    );
+}
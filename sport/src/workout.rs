@@ -1,4 +1,5 @@
 use crate::duration::DurationExt;
+use crate::effort::Met;
 use crate::item::Item;
 use crate::item_list::ItemList;
 use crate::sequence::Sequence;
@@ -71,6 +72,29 @@ impl Workout {
     pub fn item(&self) -> &Item {
         &self.item
     }
+    pub fn set_item(&mut self, item: Item) {
+        self.item = item;
+    }
+    pub fn difficulty(&self) -> Option<Difficulty> {
+        self.difficulty
+    }
+    pub fn set_difficulty(&mut self, difficulty: Option<Difficulty>) {
+        self.difficulty = difficulty;
+    }
+    /// Item effort modulated by the workout difficulty, rest is never modulated
+    pub fn met(&self) -> Met {
+        if self.is_rest() {
+            return self.item.met();
+        }
+        self.item.met().at(self.difficulty)
+    }
+    /// Estimated kilocalories for a body weight in kilograms
+    pub fn calories(&self, weight: f64) -> f64 {
+        self.met().calories(weight, *self.duration())
+    }
+    pub fn load(&self) -> f64 {
+        self.met().load(*self.duration())
+    }
     pub fn description(&self) -> &Option<String> {
         self.item.description()
     }
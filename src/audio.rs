@@ -5,6 +5,9 @@ use sport::player::Player;
 use sport::sound::Sound;
 use web_sys::wasm_bindgen::JsCast;
 
+pub const BELL: Asset = asset!("/assets/Bell.mp3");
+pub const BEEP: Asset = asset!("/assets/Beep.mp3");
+
 #[derive(Clone)]
 pub struct AudioPlayer;
 
@@ -45,9 +48,7 @@ fn audio(sound: &Sound) -> Result<web_sys::HtmlAudioElement, ErrorKind> {
         .ok_or_else(|| ErrorKind::RuntimeError("cannot get document".to_string()))?;
     let sound = document
         .get_element_by_id(sound.to_string().as_str())
-        .ok_or_else(|| {
-            ErrorKind::RuntimeError(format!("cannot get sound element {}", sound.to_string()))
-        })?;
+        .ok_or_else(|| ErrorKind::RuntimeError(format!("cannot get sound element {sound}")))?;
     sound
         .dyn_into::<web_sys::HtmlAudioElement>()
         .map_err(|_| ErrorKind::PlayerError("cannot cast to HtmlAudioElement".to_string()))
@@ -58,13 +59,9 @@ pub fn play(sound: &Sound) -> Result<(), ErrorKind> {
         gloo::dialogs::alert("sequence is silent");
         return Ok(());
     }
-    let promise = audio(sound)?.play().map_err(|err| {
-        ErrorKind::PlayerError(format!(
-            "cannot play sound {}: {:?}",
-            sound.to_string(),
-            err
-        ))
-    })?;
+    let promise = audio(sound)?
+        .play()
+        .map_err(|err| ErrorKind::PlayerError(format!("cannot play sound {sound}: {err:?}")))?;
     wasm_bindgen_futures::spawn_local(async move {
         let future = wasm_bindgen_futures::JsFuture::from(promise);
         if let Err(err) = future.await {
@@ -80,13 +77,13 @@ pub fn Sounds() -> Element {
         div { id: "sounds",
             audio {
                 id: Sound::Bell.to_string(),
-                src: asset!("/assets/Bell.mp3"),
+                src: BELL,
                 preload: "auto",
                 autoplay: false,
             }
             audio {
                 id: Sound::Beep.to_string(),
-                src: asset!("/assets/Beep.mp3"),
+                src: BEEP,
                 preload: "auto",
                 autoplay: false,
             }
@@ -1,66 +1,227 @@
+use crate::audio::AudioPlayer;
 use crate::audio::Sounds;
+use crate::download;
 use crate::global::Global;
 use crate::routes;
 use crate::routes::Route;
+use crate::settings::{Settings, today};
+use crate::sync;
 use dioxus::prelude::*;
 use itertools::Itertools;
-use sport::defaults::SEQUENCES;
-use sport::defaults::{NEXT_ITEM, PREVIOUS_ITEM, RANDOMIZE, RESTART_SEQUENCE, SIGNAL};
+use sport::card::Card;
+use sport::csv;
+use sport::defaults::{
+    DURATION_CHOICES, NEXT_ITEM, PREVIOUS_ITEM, RANDOMIZE, RESTART_SEQUENCE, SIGNAL,
+};
+use sport::defaults::{PROGRAMS, SEQUENCES, TEMPLATES};
 use sport::duration::DurationExt;
+use sport::export;
+use sport::heart_rate::HeartRate;
+use sport::i18n::{Locale, Text};
+use sport::item::Item;
 use sport::item_list::ItemList;
-use sport::timer::Timer;
+use sport::keys::{Action, label};
+use sport::program::{Day, Enrollment, Program};
+use sport::protocol::Message;
+use sport::sequence::Sequence;
+use sport::session::Session;
+use sport::signal::SoundSignal;
+use sport::tag::Equipment;
+use sport::template::{ParameterKind, Template, Values};
+use sport::timer::{Phase, Timer};
+use strum::VariantArray;
+use web_sys::wasm_bindgen::JsCast;
+
+/// Focus is in a form field, keys go to the field and not to the shortcuts
+fn editing() -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element())
+        .is_some_and(|element| {
+            matches!(element.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA")
+                || element
+                    .dyn_ref::<web_sys::HtmlElement>()
+                    .is_some_and(web_sys::HtmlElement::is_content_editable)
+        })
+}
 
 #[component]
-pub fn SequenceTimer(slug: String) -> Element {
-    let Some(global) = Global::new(false, 10, &slug) else {
-        return rsx! { "unknown sequence" };
+pub fn SequenceTimer(
+    slug: String,
+    seed: String,
+    level: i8,
+    minutes: u64,
+    rounds: u64,
+    work: u64,
+    rest: u64,
+    room: String,
+) -> Element {
+    let values: Values = [
+        (ParameterKind::Rounds, rounds),
+        (ParameterKind::Work, work),
+        (ParameterKind::Rest, rest),
+    ]
+    .into_iter()
+    .filter(|(_, value)| *value > 0)
+    .collect();
+    let settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let Some(sequence) = settings.plan(&slug, &values, level, minutes, &seed) else {
+        return rsx! { {locale.text(Text::UnknownSequence)} };
     };
+    let route = Route::template(slug.clone(), &values)
+        .with_seed(seed.clone())
+        .with_level(level)
+        .with_minutes(minutes)
+        .with_room(room.clone());
+    let global = Global::new(false, 10, &slug, &sequence, &room);
     if global.timer.read().sequence().is_empty() {
-        return rsx! { "empty sequence" };
+        return rsx! { {locale.text(Text::EmptySequence)} };
     }
     let mut global = use_context_provider(|| global);
+    let mut help = use_signal(|| false);
+    let mut display = use_signal(|| false);
+    let mut rebinding = use_signal(|| None::<Action>);
     let timer = global.timer.read();
+    let mut keys = settings.keys;
     rsx! {
         Sounds {}
-        div { id: "timer", class: "grid gap-4 grid-cols-1 text-3xl p-2",
+        div {
+            id: "timer",
+            class: "grid gap-4 grid-cols-1 text-3xl p-2",
+            tabindex: 0,
+            onmounted: move |event| async move {
+                let _ = event.set_focus(true).await;
+            },
+            onkeydown: move |event| {
+                let key = event.key().to_string();
+                if let Some(action) = rebinding.take() {
+                    keys.write().bind(&key, action);
+                    event.prevent_default();
+                    return;
+                }
+                if editing() {
+                    return;
+                }
+                let Some(action) = keys.read().action(&key) else {
+                    return;
+                };
+                event.prevent_default();
+                match action {
+                    Action::Toggle => global.timer.with_mut(Timer::toggle),
+                    Action::Previous => global.timer.with_mut(Timer::manual_previous),
+                    Action::Next => global.timer.with_mut(Timer::manual_next),
+                    Action::RestartWorkout => global.timer.with_mut(Timer::restart_workout),
+                    Action::Mute => global.sound_signal.with_mut(sport::signal::SoundSignal::toggle),
+                    Action::Ring => global.timer.with(Timer::ring),
+                    Action::Help => help.toggle(),
+                }
+            },
+            // inside the timer so that key presses in the overlay bubble up to the bindings
+            if help() {
+                KeyHelp { help, rebinding }
+            }
+            if display() {
+                GymDisplay { display }
+            }
             Controls {}
             div { class: "flex items-center justify-center",
                 button {
                     id: "current_workout",
-                    title: "Restart workout",
+                    title: locale.text(Text::RestartWorkout),
                     onclick: move |_| global.timer.with_mut(Timer::restart_workout),
-                    {timer.label()}
+                    {timer.localized_label(locale)}
                 }
             }
-            div { class: "flex items-center justify-center",
-                span { id: "counter", title: "Time left", {global.timer.read().left().to_string()} }
+            if timer.phase().is_finished() {
+                SessionSummary { slug: slug.clone() }
+            } else {
+                if let Some(workout) = timer.sequence().current() {
+                    ItemGuide { item: workout.item().clone() }
+                }
+                div { class: "flex items-center justify-center",
+                    span { id: "counter", title: locale.text(Text::TimeLeft), {global.timer.read().left().to_string()} }
+                }
+                if let Some(next_workout) = global.timer.read().sequence().next_workout() {
+                    div { class: "flex items-center justify-center",
+                        span { id: "next_exercise",
+                            {format!("{}: ", locale.text(Text::Next))}
+                            {locale.item_name(next_workout.item())}
+                        }
+                    }
+                } else {
+                    span { class: "flex items-center justify-center", {locale.text(Text::End)} }
+                }
             }
-            if let Some(next_workout) = global.timer.read().sequence().next_workout() {
+            if let Some(seed) = global.timer.read().sequence().seed() {
                 div { class: "flex items-center justify-center",
-                    span { id: "next_exercise",
-                        {"Next: "}
-                        {next_workout.item().name()}
+                    Link {
+                        id: "seed_link",
+                        title: locale.text(Text::ShareSeed),
+                        to: route.clone().with_seed(seed.to_string()),
+                        {format!("{}: {seed}", locale.text(Text::Seed))}
+                    }
+                }
+            }
+            div { class: "flex items-center justify-evenly",
+                Link {
+                    id: "easier_link",
+                    title: locale.text(Text::EasierVariants),
+                    to: route.clone().with_level(level.saturating_sub(1)),
+                    {locale.text(Text::Easier)}
+                }
+                Link {
+                    id: "harder_link",
+                    title: locale.text(Text::HarderVariants),
+                    to: route.clone().with_level(level.saturating_add(1)),
+                    {locale.text(Text::Harder)}
+                }
+            }
+            DurationPicker { route: route.clone(), minutes }
+            RoomPicker { route: route.clone(), room: room.clone() }
+            div { class: "flex items-center justify-center",
+                span { id: "effort", title: locale.text(Text::EstimatedEffort),
+                    {
+                        format!(
+                            "~{:.0} kcal, {} {:.0}",
+                            timer.sequence().calories(*settings.weight.read()),
+                            locale.text(Text::Load),
+                            timer.sequence().load(),
+                        )
                     }
                 }
-            } else {
-                span { class: "flex items-center justify-center", "END" }
             }
             div { class: "flex items-center justify-center",
                 Link {
                     id: "exercises_link",
-                    title: "See exercises in this sequence",
-                    to: routes::Route::Workouts {
-                        slug: global.timer.read().sequence().slug(),
+                    title: locale.text(Text::SeeExercisesTitle),
+                    to: route.workouts(),
+                    {locale.text(Text::SeeExercises)}
+                }
+            }
+            div { class: "flex items-center justify-center",
+                button {
+                    id: "gym_display",
+                    title: locale.text(Text::GymDisplay),
+                    onclick: move |_| {
+                        display.set(true);
+                        document::eval(ENTER_DISPLAY);
                     },
-                    {"See exercises"}
+                    "📺"
+                }
+                button {
+                    id: "keys_help",
+                    title: locale.text(Text::KeyboardShortcuts),
+                    onclick: move |_| help.toggle(),
+                    "⌨"
                 }
             }
             div { class: "flex items-center justify-center",
                 Link {
                     id: "home_link",
-                    title: "Go to sequence list",
+                    title: locale.text(Text::GoHome),
                     to: routes::Route::Sequences {},
-                    {"Home"}
+                    {locale.text(Text::Home)}
                 }
             }
         }
@@ -105,36 +266,276 @@ pub fn SequenceTimer(slug: String) -> Element {
 //     }
 // }
 
+/// Fullscreen and keep the screen awake, the wake lock is lost when the page is hidden
+const ENTER_DISPLAY: &str = r"
+    const lock = async () => {
+        if ('wakeLock' in navigator) {
+            try { window.wakeLock = await navigator.wakeLock.request('screen'); } catch (e) {}
+        }
+    };
+    if (!window.wakeLockListener) {
+        window.wakeLockListener = () => {
+            if (window.wakeLock && document.visibilityState === 'visible') { lock(); }
+        };
+        document.addEventListener('visibilitychange', window.wakeLockListener);
+    }
+    await lock();
+    if (document.documentElement.requestFullscreen) {
+        try { await document.documentElement.requestFullscreen(); } catch (e) {}
+    }
+";
+
+const EXIT_DISPLAY: &str = r"
+    if (window.wakeLock) { await window.wakeLock.release(); window.wakeLock = null; }
+    if (document.fullscreenElement) { await document.exitFullscreen(); }
+";
+
+/// What was done once the last workout ends, with ways to carry on
+#[component]
+fn SessionSummary(slug: String) -> Element {
+    let mut global = use_context::<Global>();
+    let mut settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let summary = global.timer.read().summary();
+    let sequence_slug = global.timer.read().sequence().slug();
+    let rpe = settings
+        .history
+        .read()
+        .last()
+        .filter(|session| session.slug() == sequence_slug)
+        .and_then(Session::rpe);
+    let next = settings.next(&slug);
+    let line = |text: Text, done: std::time::Duration, planned: std::time::Duration| {
+        if done == planned {
+            format!("{}: {}", locale.text(text), done.to_string())
+        } else {
+            format!(
+                "{}: {} / {}",
+                locale.text(text),
+                done.to_string(),
+                planned.to_string()
+            )
+        }
+    };
+    rsx! {
+        div { id: "summary", class: "grid gap-2 justify-center text-center",
+            h2 { {locale.text(Text::Finished)} }
+            span { id: "summary_work", {line(Text::Work, summary.work, summary.planned_work)} }
+            span { id: "summary_rest", {line(Text::Rest, summary.rest, summary.planned_rest)} }
+            span { id: "summary_spent",
+                {format!("{}: {}", locale.text(Text::TimeSpent), summary.spent().to_string())}
+            }
+            span { id: "summary_pauses",
+                {
+                    format!(
+                        "{}: {} ({})",
+                        locale.text(Text::Pauses),
+                        summary.pauses,
+                        summary.paused.to_string(),
+                    )
+                }
+            }
+            if !summary.skipped.is_empty() {
+                span { id: "summary_skipped",
+                    {format!("{}: {}", locale.text(Text::Skipped), summary.skipped.join(", "))}
+                }
+            }
+            label {
+                {format!("{} ", locale.text(Text::Effort))}
+                select {
+                    id: "summary_rpe",
+                    onchange: move |event| {
+                        let Ok(rpe) = event.value().parse::<u8>() else {
+                            return;
+                        };
+                        if let Some(session) = settings
+                            .history
+                            .write()
+                            .last_mut()
+                            .filter(|session| session.slug() == sequence_slug)
+                        {
+                            session.set_rpe(rpe);
+                        }
+                    },
+                    option { value: "", selected: rpe.is_none(), "-" }
+                    for value in 1..=10_u8 {
+                        option { value, selected: rpe == Some(value), "{value}" }
+                    }
+                }
+            }
+            div { class: "flex items-center justify-evenly",
+                button {
+                    id: "summary_restart",
+                    onclick: move |_| global.timer.with_mut(Timer::restart_sequence),
+                    {locale.text(Text::RestartSequence)}
+                }
+                if let Some(next) = next {
+                    Link { id: "summary_next", to: Route::timer(next.slug()),
+                        {format!("{}: {}", locale.text(Text::NextSequence), next)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Background class of the current timer phase
+fn phase_class(timer: &Timer) -> &'static str {
+    match timer.phase() {
+        Phase::Finished => "phase_finished",
+        Phase::Preparing if timer.left().as_secs() <= 3 => "phase_last_seconds",
+        Phase::Preparing => "phase_prepare",
+        _ if timer.sequence().last_seconds() => "phase_last_seconds",
+        Phase::Resting => "phase_rest",
+        Phase::Working => "phase_work",
+    }
+}
+
+/// Countdown filling the screen, readable from the bag
+#[component]
+pub fn GymDisplay(display: Signal<bool>) -> Element {
+    let global = use_context::<Global>();
+    let locale = *use_context::<Settings>().locale.read();
+    let timer = global.timer.read();
+    rsx! {
+        div {
+            id: "gym_display",
+            class: format!("gym_display {}", phase_class(&timer)),
+            title: locale.text(Text::DoubleTapToLeave),
+            ondoubleclick: move |_| {
+                display.set(false);
+                document::eval(EXIT_DISPLAY);
+            },
+            span { class: "gym_label", {timer.localized_label(locale)} }
+            span { class: "gym_counter", {timer.left().to_string()} }
+            if let Some(next_workout) = timer.sequence().next_workout() {
+                span { class: "gym_label",
+                    {format!("{}: {}", locale.text(Text::Next), locale.item_name(next_workout.item()))}
+                }
+            }
+        }
+    }
+}
+
+/// Shortcut list, choosing an action rebinds it to the next pressed key
+#[component]
+pub fn KeyHelp(help: Signal<bool>, rebinding: Signal<Option<Action>>) -> Element {
+    let settings = use_context::<Settings>();
+    let keys = settings.keys.read();
+    let locale = *settings.locale.read();
+    rsx! {
+        div {
+            id: "key_help",
+            class: "fixed inset-0 z-10 flex items-center justify-center bg-black/80 text-white",
+            table { class: "text-xl",
+                for action in Action::VARIANTS {
+                    tr {
+                        td { class: "p-2", {locale.text(action.text())} }
+                        td { class: "p-2",
+                            {keys.keys(*action).into_iter().map(label).join(", ")}
+                        }
+                        td { class: "p-2",
+                            button {
+                                title: locale.text(Text::PressKey),
+                                onclick: move |_| rebinding.set(Some(*action)),
+                                if rebinding() == Some(*action) {
+                                    "…"
+                                } else {
+                                    {locale.text(Text::Change)}
+                                }
+                            }
+                        }
+                    }
+                }
+                tr {
+                    td { class: "p-2",
+                        button { onclick: move |_| help.set(false), {locale.text(Text::Close)} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn DurationPicker(route: Route, minutes: u64) -> Element {
+    let global = use_context::<Global>();
+    let locale = *use_context::<Settings>().locale.read();
+    let total = global.timer.read().sequence().total();
+    rsx! {
+        div { class: "flex items-center justify-center",
+            label { r#for: "duration_picker",
+                {format!("{} {} ", locale.text(Text::Total), total.to_string())}
+            }
+            select {
+                id: "duration_picker",
+                title: locale.text(Text::ScaleDuration),
+                onchange: move |event| {
+                    navigator()
+                        .push(route.clone().with_minutes(event.value().parse().unwrap_or_default()));
+                },
+                option { value: "0", selected: minutes == 0, {locale.text(Text::Original)} }
+                for choice in DURATION_CHOICES {
+                    option {
+                        value: choice.to_string(),
+                        selected: minutes == *choice,
+                        {format!("{choice} {}", locale.text(Text::Minutes))}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn RoomPicker(route: Route, room: String) -> Element {
+    let locale = *use_context::<Settings>().locale.read();
+    rsx! {
+        div { class: "flex items-center justify-center text-base",
+            label { r#for: "room_picker", {format!("{} ", locale.text(Text::BroadcastToRoom))} }
+            input {
+                id: "room_picker",
+                title: locale.text(Text::BroadcastTitle),
+                value: room,
+                onchange: move |event| {
+                    navigator().push(route.clone().with_room(event.value().trim().to_string()));
+                },
+            }
+        }
+    }
+}
+
 #[component]
 pub fn Controls() -> Element {
     let mut global = use_context::<Global>();
+    let locale = *use_context::<Settings>().locale.read();
     rsx! {
         div { id: "controls", class: "flex justify-evenly p-2",
             button {
                 id: "toggle_timer",
                 class: "rounded-full text-3xl",
-                title: global.timer.read().status().next_title(),
+                title: locale.text(global.timer.read().status().next_text()),
                 onclick: move |_| global.timer.with_mut(Timer::toggle),
                 {global.timer.read().status().next().to_string()}
             }
             button {
                 id: "restart_sequence",
                 class: "rounded-full text-3xl",
-                title: "Restart sequence",
+                title: locale.text(Text::RestartSequence),
                 onclick: move |_| global.timer.with_mut(Timer::restart_sequence),
                 {RESTART_SEQUENCE}
             }
             button {
                 id: "previous_workout",
                 class: "rounded-full text-3xl",
-                title: "Previous workout",
+                title: locale.text(Text::PreviousWorkout),
                 onclick: move |_| global.timer.with_mut(Timer::manual_previous),
                 {PREVIOUS_ITEM}
             }
             button {
                 id: "next_workout",
                 class: "rounded-full text-3xl",
-                title: "Next workout",
+                title: locale.text(Text::NextWorkout),
                 onclick: move |_| global.timer.with_mut(Timer::manual_next),
                 {NEXT_ITEM}
             }
@@ -142,7 +543,7 @@ pub fn Controls() -> Element {
                 button {
                     id: "randomize",
                     class: "rounded-full text-3xl",
-                    title: "Shuffle sequence",
+                    title: locale.text(Text::ShuffleSequence),
                     onclick: move |_| global.timer.with_mut(Timer::shuffle),
                     {RANDOMIZE}
                 }
@@ -151,7 +552,7 @@ pub fn Controls() -> Element {
                 button {
                     id: "toggle_signal",
                     class: "text-3xl",
-                    title: global.sound_signal.read().state().next_title(),
+                    title: locale.text(global.sound_signal.read().state().next_text()),
                     onclick: move |_| global.sound_signal.with_mut(sport::signal::SoundSignal::toggle),
                     input {
                         r#type: "checkbox",
@@ -163,7 +564,7 @@ pub fn Controls() -> Element {
                 button {
                     id: "emit_signal",
                     class: "text-3xl",
-                    title: "Emit signal sound",
+                    title: locale.text(Text::EmitSignal),
                     onclick: move |_| { global.timer.with(|t| { t.ring() }) },
                     {SIGNAL}
                 }
@@ -172,19 +573,149 @@ pub fn Controls() -> Element {
     }
 }
 
+#[component]
+pub fn EquipmentPicker() -> Element {
+    let mut settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    rsx! {
+        details { id: "equipment",
+            summary { {locale.text(Text::Equipment)} }
+            for equipment in Equipment::VARIANTS.iter().filter(|e| **e != Equipment::None) {
+                label { class: "p-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: settings.equipment.read().contains(equipment),
+                        onchange: move |_| settings.equipment.write().toggle(*equipment),
+                    }
+                    {locale.tag(&(*equipment).into())}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn WeightPicker() -> Element {
+    let mut settings = use_context::<Settings>();
+    rsx! {
+        label { id: "weight", class: "p-1",
+            {format!("{} ", settings.locale.read().text(Text::BodyWeight))}
+            input {
+                r#type: "number",
+                min: 20,
+                max: 250,
+                value: *settings.weight.read(),
+                onchange: move |event| {
+                    if let Ok(weight) = event.value().parse::<f64>() && weight > 0.0 {
+                        settings.weight.set(weight);
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn LocalePicker() -> Element {
+    let mut settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    rsx! {
+        label { id: "locale", class: "p-1",
+            {format!("{} ", locale.text(Text::Language))}
+            select {
+                onchange: move |event| {
+                    if let Some(locale) = Locale::VARIANTS
+                        .iter()
+                        .find(|locale| locale.code() == event.value())
+                    {
+                        settings.locale.set(*locale);
+                    }
+                },
+                for choice in Locale::VARIANTS {
+                    option {
+                        value: choice.code(),
+                        selected: locale == *choice,
+                        {choice.to_string()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn SyncSettings() -> Element {
+    let mut settings = use_context::<Settings>();
+    let mut room = use_signal(String::new);
+    let locale = *settings.locale.read();
+    rsx! {
+        details { id: "sync",
+            summary { {locale.text(Text::Sync)} }
+            label { class: "p-1",
+                {format!("{} ", locale.text(Text::Relay))}
+                input {
+                    value: settings.relay.read().clone(),
+                    onchange: move |event| settings.relay.set(event.value().trim().to_string()),
+                }
+            }
+            label { class: "p-1",
+                {format!("{} ", locale.text(Text::Room))}
+                input {
+                    value: room.read().clone(),
+                    oninput: move |event| room.set(event.value().trim().to_string()),
+                }
+            }
+            Link {
+                id: "follow_link",
+                title: locale.text(Text::FollowTitle),
+                to: Route::Follower { room: room() },
+                {locale.text(Text::Follow)}
+            }
+        }
+    }
+}
+
 #[component]
 pub fn Sequences() -> Element {
+    let mut settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let imported = settings.sequences.read().clone();
+    let sequences = imported
+        .iter()
+        .chain(settings.equipment.read().sequences(&SEQUENCES))
+        .collect_vec();
     rsx! {
+        LocalePicker {}
+        EquipmentPicker {}
+        WeightPicker {}
+        SyncSettings {}
+        Link { id: "history_link", to: Route::History {}, {locale.text(Text::History)} }
+        Link { id: "today_link", to: Route::Today {}, {locale.text(Text::Today)} }
+        ul { id: "templates",
+            for template in TEMPLATES.iter() {
+                TemplateCard { template: template.clone() }
+            }
+        }
+        CsvImport {}
         ul { id: "sequences",
-            for sequence in SEQUENCES.iter() {
+            for sequence in sequences {
                 li { id: format!("sequence_{}", sequence.slug()),
                     Link {
-                        to: Route::SequenceTimer {
-                            slug: sequence.slug(),
-                        },
-                        title: format!("Start timer for {}", sequence.name()),
+                        to: Route::timer(sequence.slug()),
+                        title: format!("{} {}", locale.text(Text::StartTimerFor), sequence.name()),
                         {sequence.to_string()}
                     }
+                    if imported.contains(sequence) {
+                        button {
+                            class: "p-1",
+                            title: locale.text(Text::Remove),
+                            onclick: {
+                                let slug = sequence.slug();
+                                move |_| settings.remove(&slug)
+                            },
+                            "✖"
+                        }
+                    }
                 }
             }
         }
@@ -192,6 +723,38 @@ pub fn Sequences() -> Element {
     // span { {format!("Sequences: {}", SEQUENCES.len())} }
 }
 
+#[component]
+pub fn TemplateCard(template: Template) -> Element {
+    let locale = *use_context::<Settings>().locale.read();
+    let mut values = use_signal(|| template.defaults());
+    let sequence = template.instantiate(&values.read());
+    rsx! {
+        li { id: format!("template_{}", template.slug()),
+            Link {
+                to: Route::template(template.slug(), &values.read()),
+                title: format!("{} {}", locale.text(Text::StartTimerFor), sequence.name()),
+                {sequence.to_string()}
+            }
+            for parameter in template.parameters().into_iter().filter(|p| !p.is_fixed()).cloned() {
+                label { class: "p-1",
+                    {format!(" {} ", locale.parameter(parameter.kind()))}
+                    input {
+                        r#type: "number",
+                        min: parameter.min(),
+                        max: parameter.max(),
+                        step: parameter.step(),
+                        value: values.read().get(&parameter.kind()).copied().unwrap_or_default(),
+                        onchange: move |event| {
+                            let value = parameter.value(event.value().parse().unwrap_or_default());
+                            values.write().insert(parameter.kind(), value);
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 pub fn SequencesJson() -> Element {
     rsx! {
@@ -201,13 +764,14 @@ pub fn SequencesJson() -> Element {
 
 #[component]
 pub fn Tags() -> Element {
+    let locale = *use_context::<Settings>().locale.read();
     let tags = ItemList::tags();
     rsx! {
-        span { {format!("Tags: {}", tags.len())} }
+        span { {format!("{}: {}", locale.text(Text::Tags), tags.len())} }
         ul { id: "tags",
             for tag in tags {
                 li { id: format!("tag_{}", tag.slug()),
-                    Link { to: Route::Items { slug: tag.slug() }, {tag.to_string()} }
+                    Link { to: Route::Items { slug: tag.slug() }, {locale.tag(&tag)} }
                 }
             }
         }
@@ -216,6 +780,7 @@ pub fn Tags() -> Element {
 
 #[component]
 pub fn Items(slug: String) -> Element {
+    let settings = use_context::<Settings>();
     let items = if slug.is_empty() {
         ItemList::items()
     } else {
@@ -225,26 +790,724 @@ pub fn Items(slug: String) -> Element {
             .flat_map(|(_, items)| items.iter().cloned().collect_vec())
             .collect_vec()
     };
+    let items = items
+        .into_iter()
+        .filter(|item| settings.equipment.read().allows(item))
+        .collect_vec();
+    let locale = *settings.locale.read();
     rsx! {
-        span { {format!("Items: {}", items.len())} }
+        span { {format!("{}: {}", locale.text(Text::Items), items.len())} }
         ul { id: "items",
             for item in items.iter() {
-                li { id: format!("item_{}", item.slug()), {item.to_string()} }
+                li { id: format!("item_{}", item.slug()),
+                    Link {
+                        to: Route::ItemDetail {
+                            slug: item.slug(),
+                            sequence: String::new(),
+                        },
+                        {locale.item(item)}
+                    }
+                }
             }
         }
     }
 }
 
 #[component]
-pub fn Workouts(slug: String) -> Element {
-    let Some(sequence) = SEQUENCES.iter().find(|s| s.slug() == slug) else {
-        return rsx! { "unknown sequence" };
+pub fn Workouts(
+    slug: String,
+    seed: String,
+    level: i8,
+    minutes: u64,
+    rounds: u64,
+    work: u64,
+    rest: u64,
+) -> Element {
+    let values: Values = [
+        (ParameterKind::Rounds, rounds),
+        (ParameterKind::Work, work),
+        (ParameterKind::Rest, rest),
+    ]
+    .into_iter()
+    .filter(|(_, value)| *value > 0)
+    .collect();
+    let settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let Some(sequence) = settings.plan(&slug, &values, level, minutes, &seed) else {
+        return rsx! { {locale.text(Text::UnknownSequence)} };
     };
 
     rsx! {
         ul { id: "workouts",
             for item in sequence.unique_items().iter() {
-                li { id: format!("item_{}", item.slug()), {item.name()} }
+                li { id: format!("item_{}", item.slug()),
+                    Link {
+                        to: Route::ItemDetail {
+                            slug: item.slug(),
+                            sequence: slug.clone(),
+                        },
+                        {locale.item_name(item)}
+                    }
+                }
+            }
+        }
+        CardExport { sequence: sequence.clone() }
+        SequenceCsv { sequence }
+    }
+}
+
+#[component]
+pub fn History() -> Element {
+    let settings = use_context::<Settings>();
+    let history = settings.history.read();
+    let locale = *settings.locale.read();
+    #[allow(clippy::cast_possible_truncation)]
+    let week_ago = (js_sys::Date::now() / 1000.0) as i64 - 7 * 24 * 3600;
+    let week = history.since(week_ago);
+    rsx! {
+        div { id: "statistics",
+            p {
+                {
+                    format!(
+                        "{}: {} {}, {}, ~{:.0} kcal, {} {:.0}",
+                        locale.text(Text::LastWeek),
+                        week.len(),
+                        locale.text(Text::Sessions),
+                        week.duration().to_string(),
+                        week.calories(),
+                        locale.text(Text::Load),
+                        week.load(),
+                    )
+                }
+            }
+            p {
+                {
+                    format!(
+                        "{}: {} {}, {}, ~{:.0} kcal, {} {:.0}",
+                        locale.text(Text::AllTime),
+                        history.len(),
+                        locale.text(Text::Sessions),
+                        history.duration().to_string(),
+                        history.calories(),
+                        locale.text(Text::Load),
+                        history.load(),
+                    )
+                }
+            }
+        }
+        ul { id: "history",
+            for (index, session) in history.iter().enumerate().rev() {
+                li {
+                    {
+                        format!(
+                            "{} {} : {}, ~{:.0} kcal, {} {:.0}{}",
+                            time::OffsetDateTime::from_unix_timestamp(session.started())
+                                .map(|started| started.date().to_string())
+                                .unwrap_or_default(),
+                            session.name(),
+                            session.duration().to_string(),
+                            session.calories(),
+                            locale.text(Text::Load),
+                            session.load(),
+                            session.rpe().map(|rpe| format!(", RPE {rpe}")).unwrap_or_default(),
+                        )
+                    }
+                    SessionExport { session: session.clone() }
+                    HeartRateImport { index, started: session.started() }
+                    if !session.heart_rate().is_empty() {
+                        HeartRateChart { session: session.clone() }
+                    }
+                }
+            }
+        }
+        Link { id: "home_link", to: Route::Sequences {}, {settings.locale.read().text(Text::Home)} }
+    }
+}
+
+#[component]
+pub fn Programs() -> Element {
+    let mut settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    rsx! {
+        ul { id: "programs",
+            for program in PROGRAMS.iter() {
+                li { id: format!("program_{}", program.slug()),
+                    h2 { {program.to_string()} }
+                    if let Some(description) = program.description() {
+                        p { {description.clone()} }
+                    }
+                    ul {
+                        for planned in program.days() {
+                            li {
+                                {
+                                    format!(
+                                        "{} : {}",
+                                        locale.weekday(planned.weekday()),
+                                        planned.sequence(0).map(|s| s.to_string()).unwrap_or_default(),
+                                    )
+                                }
+                            }
+                        }
+                    }
+                    button {
+                        onclick: move |_| {
+                            settings.program.set(Some(Enrollment::new(program, today())));
+                            navigator().push(Route::Today {});
+                        },
+                        {format!("{} ({} {})", locale.text(Text::StartProgram), program.weeks(), locale.text(Text::Week))}
+                    }
+                }
+            }
+        }
+        Link { id: "home_link", to: Route::Sequences {}, {locale.text(Text::Home)} }
+    }
+}
+
+/// The sequence planned today by the followed program and the program calendar
+#[component]
+pub fn Today() -> Element {
+    let mut settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let Some(enrollment) = settings.program.read().clone() else {
+        return rsx! {
+            Link { id: "programs_link", to: Route::Programs {}, {locale.text(Text::Programs)} }
+            Link { id: "home_link", to: Route::Sequences {}, {locale.text(Text::Home)} }
+        };
+    };
+    let Some(program) = enrollment.program() else {
+        return rsx! {
+            Link { id: "programs_link", to: Route::Programs {}, {locale.text(Text::Programs)} }
+        };
+    };
+    let date = today();
+    let (done, total) = enrollment.progress();
+    let planned = enrollment.day(date).and_then(|day| {
+        let planned = program.planned(day.weekday)?;
+        Some((day, planned.clone(), planned.sequence(day.week)?))
+    });
+    rsx! {
+        h2 { id: "program_name", {program.to_string()} }
+        p { {format!("{done}/{total} {}", locale.text(Text::Done).to_lowercase())} }
+        div { id: "today",
+            match planned {
+                Some((day, planned, sequence)) => rsx! {
+                    p { {format!("{} {}/{}", locale.text(Text::Week), day.week + 1, program.weeks())} }
+                    Link {
+                        to: Route::template(planned.slug().to_string(), &planned.values(day.week)),
+                        title: format!("{} {}", locale.text(Text::StartTimerFor), sequence.name()),
+                        {sequence.to_string()}
+                    }
+                    if enrollment.is_completed(day) {
+                        span { class: "ml-2", "✅" }
+                    } else {
+                        button {
+                            class: "ml-2 underline",
+                            onclick: move |_| {
+                                if let Some(enrollment) = settings.program.write().as_mut() {
+                                    enrollment.complete(day);
+                                }
+                            },
+                            {locale.text(Text::Done)}
+                        }
+                    }
+                },
+                None => rsx! {
+                    p { {locale.text(Text::RestDay)} }
+                },
+            }
+        }
+        table { id: "program_calendar",
+            for week in 0..program.weeks() {
+                tr {
+                    th { {format!("{} {}", locale.text(Text::Week), week + 1)} }
+                    for planned in program.days() {
+                        {
+                            let day = Day { week, weekday: planned.weekday() };
+                            let date = enrollment.date(day).map(|date| date.to_string()).unwrap_or_default();
+                            let mark = if enrollment.is_completed(day) { "✅" } else { "⬜" };
+                            rsx! {
+                                td { title: date, {format!("{mark} {}", locale.weekday(planned.weekday()))} }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        CalendarExport { enrollment: enrollment.clone() }
+        button { id: "stop_program", onclick: move |_| settings.program.set(None), "✖ {program.name()}" }
+        Link { id: "programs_link", to: Route::Programs {}, {locale.text(Text::Programs)} }
+        Link { id: "home_link", to: Route::Sequences {}, {locale.text(Text::Home)} }
+    }
+}
+
+/// Download the program as calendar events at a chosen time of day
+#[component]
+fn CalendarExport(enrollment: Enrollment) -> Element {
+    let mut at = use_signal(|| time::Time::from_hms(18, 0, 0).unwrap_or(time::Time::MIDNIGHT));
+    let format = time::macros::format_description!("[hour]:[minute]");
+    let export = move |_| {
+        let origin = web_sys::window()
+            .and_then(|window| window.location().origin().ok())
+            .unwrap_or_default();
+        #[allow(clippy::cast_possible_truncation)]
+        let now = (js_sys::Date::now() / 1000.0) as i64;
+        let calendar = export::ics(&enrollment, at(), now, |planned, day| {
+            let route = Route::template(planned.slug().to_string(), &planned.values(day.week));
+            format!("{origin}{route}")
+        });
+        let name = enrollment.program().map(Program::slug).unwrap_or_default();
+        download::save(&format!("{name}.ics"), "text/calendar", calendar.as_bytes());
+    };
+    rsx! {
+        div { id: "calendar_export",
+            input {
+                r#type: "time",
+                value: at.read().format(format).unwrap_or_default(),
+                onchange: move |event| {
+                    if let Ok(time) = time::Time::parse(&event.value(), format) {
+                        at.set(time);
+                    }
+                },
+            }
+            button { class: "ml-2 underline", onclick: export, "📅 .ics" }
+        }
+    }
+}
+
+/// Download a printable card of the sequence
+#[component]
+fn CardExport(sequence: Sequence) -> Element {
+    let locale = *use_context::<Settings>().locale.read();
+    let card = Card::from(&sequence).with_locale(locale);
+    let name = sequence.slug();
+    let markdown = {
+        let (card, name) = (card.clone(), name.clone());
+        move |_| {
+            download::save(
+                &format!("{name}.md"),
+                "text/markdown",
+                card.markdown().as_bytes(),
+            );
+        }
+    };
+    let html = {
+        let (card, name) = (card.clone(), name.clone());
+        move |_| {
+            download::save(&format!("{name}.html"), "text/html", card.html().as_bytes());
+        }
+    };
+    let pdf = move |_| {
+        download::save(&format!("{name}.pdf"), "application/pdf", &card.pdf());
+    };
+    rsx! {
+        div { id: "card_export",
+            "🖨️"
+            button { class: "ml-2 underline", onclick: markdown, "Markdown" }
+            button { class: "ml-2 underline", onclick: html, "HTML" }
+            button { class: "ml-2 underline", onclick: pdf, "PDF" }
+        }
+    }
+}
+
+/// Download the workouts as a spreadsheet
+#[component]
+fn SequenceCsv(sequence: Sequence) -> Element {
+    let export = move |_| {
+        download::save(
+            &format!("{}.csv", sequence.slug()),
+            "text/csv",
+            csv::sequence(&sequence).as_bytes(),
+        );
+    };
+    rsx! {
+        button { id: "sequence_csv", class: "ml-2 underline", onclick: export, "CSV" }
+    }
+}
+
+/// Import sequences planned in a spreadsheet, named after the file, and export the catalog
+#[component]
+fn CsvImport() -> Element {
+    let mut settings = use_context::<Settings>();
+    let mut error = use_signal(String::new);
+    let import = move |event: FormEvent| async move {
+        let Some(files) = event.files() else {
+            return;
+        };
+        for name in files.files() {
+            let Some(bytes) = files.read_file(&name).await else {
+                continue;
+            };
+            let stem = name
+                .rsplit_once('.')
+                .map_or(name.as_str(), |(stem, _)| stem);
+            match csv::parse_sequence(stem, &String::from_utf8_lossy(&bytes)) {
+                Ok(sequence) => {
+                    error.set(String::new());
+                    settings.import(sequence);
+                }
+                Err(err) => error.set(format!("{name}: {err}")),
+            }
+        }
+    };
+    let catalog = move |_| {
+        download::save(
+            "items.csv",
+            "text/csv",
+            csv::items(&ItemList::items()).as_bytes(),
+        );
+    };
+    rsx! {
+        div { id: "csv_import",
+            label { class: "underline cursor-pointer",
+                "📥 CSV"
+                input {
+                    r#type: "file",
+                    accept: ".csv",
+                    class: "hidden",
+                    onchange: import,
+                }
+            }
+            button { class: "ml-2 underline", onclick: catalog, "items.csv" }
+            if !error.read().is_empty() {
+                span { class: "ml-2 text-red-500", {error} }
+            }
+        }
+    }
+}
+
+/// Download a session for watch ecosystems
+#[component]
+fn SessionExport(session: Session) -> Element {
+    let name = format!("{}-{}", session.slug(), session.started());
+    let tcx = {
+        let (session, name) = (session.clone(), name.clone());
+        move |_| {
+            download::save(
+                &format!("{name}.tcx"),
+                "application/vnd.garmin.tcx+xml",
+                export::tcx(&session).as_bytes(),
+            );
+        }
+    };
+    let fit = move |_| {
+        download::save(
+            &format!("{name}.fit"),
+            "application/vnd.ant.fit",
+            &export::fit(&session),
+        );
+    };
+    rsx! {
+        button { class: "ml-2 underline", onclick: tcx, "TCX" }
+        button { class: "ml-2 underline", onclick: fit, "FIT" }
+    }
+}
+
+/// Attach a heart rate strap recording to the session at `index` in the history
+#[component]
+fn HeartRateImport(index: usize, started: i64) -> Element {
+    let mut settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let mut error = use_signal(String::new);
+    let import = move |event: FormEvent| async move {
+        let Some(files) = event.files() else {
+            return;
+        };
+        for name in files.files() {
+            let Some(bytes) = files.read_file(&name).await else {
+                continue;
+            };
+            let imported = HeartRate::parse(&name, &bytes, started).and_then(|heart_rate| {
+                settings
+                    .history
+                    .write()
+                    .get_mut(index)
+                    .map_or(Ok(()), |session| session.set_heart_rate(&heart_rate))
+            });
+            match imported {
+                Ok(()) => error.set(String::new()),
+                Err(err) => error.set(err.to_string()),
+            }
+        }
+    };
+    rsx! {
+        label { class: "ml-2 underline cursor-pointer",
+            {locale.text(Text::HeartRate)}
+            input {
+                r#type: "file",
+                accept: ".tcx,.fit,.csv",
+                class: "hidden",
+                onchange: import,
+            }
+        }
+        if !error.read().is_empty() {
+            span { class: "ml-2 text-red-500", {error} }
+        }
+    }
+}
+
+const CHART_WIDTH: f64 = 600.0;
+const CHART_HEIGHT: f64 = 120.0;
+
+/// Heart rate curve over the session, rest laps shaded, with per lap figures
+#[component]
+fn HeartRateChart(session: Session) -> Element {
+    let locale = *use_context::<Settings>().locale.read();
+    let heart_rate = session.heart_rate();
+    let laps = heart_rate.align(&session);
+    let low = heart_rate
+        .iter()
+        .map(|s| s.bpm)
+        .min()
+        .unwrap_or_default()
+        .saturating_sub(10);
+    let high = heart_rate
+        .iter()
+        .map(|s| s.bpm)
+        .max()
+        .unwrap_or_default()
+        .saturating_add(10);
+    #[allow(clippy::cast_precision_loss)]
+    let seconds = (session.ended() - session.started()).max(1) as f64;
+    #[allow(clippy::cast_precision_loss)]
+    let x = |time: i64| (time - session.started()) as f64 / seconds * CHART_WIDTH;
+    let y = |bpm: u8| {
+        CHART_HEIGHT - f64::from(bpm - low) / f64::from((high - low).max(1)) * CHART_HEIGHT
+    };
+    let points = heart_rate
+        .iter()
+        .map(|sample| format!("{:.1},{:.1}", x(sample.time), y(sample.bpm)))
+        .join(" ");
+    let rests = session
+        .intervals()
+        .into_iter()
+        .filter(|(_, lap)| lap.is_rest())
+        .map(|(start, lap)| {
+            let end = start.saturating_add_unsigned(lap.duration().as_secs());
+            (x(start), x(end) - x(start))
+        })
+        .collect_vec();
+    rsx! {
+        svg {
+            class: "heart_rate_chart",
+            view_box: "0 0 {CHART_WIDTH} {CHART_HEIGHT}",
+            preserve_aspect_ratio: "none",
+            for (left , width) in rests {
+                rect {
+                    class: "heart_rate_rest",
+                    x: "{left}",
+                    y: "0",
+                    width: "{width}",
+                    height: "{CHART_HEIGHT}",
+                }
+            }
+            polyline { class: "heart_rate_line", points }
+        }
+        ol { class: "heart_rate_laps",
+            for lap in laps.into_iter().filter(|lap| lap.average.is_some()) {
+                li { class: if lap.rest { "heart_rate_rest_lap" },
+                    {
+                        format!(
+                            "{} : {} {}, {} {}",
+                            lap.name,
+                            lap.average.unwrap_or_default(),
+                            locale.text(Text::AverageBpm),
+                            lap.max.unwrap_or_default(),
+                            locale.text(Text::Max),
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Pictogram and execution steps, kept short to fit under the timer
+#[component]
+pub fn ItemGuide(item: Item) -> Element {
+    let locale = *use_context::<Settings>().locale.read();
+    let Some(instructions) = locale.item_instructions(&item) else {
+        return rsx! {};
+    };
+    rsx! {
+        div { id: "item_guide", class: "flex items-center justify-center gap-4 text-base",
+            if let Some(pictogram) = item.pictogram() {
+                div { class: "w-16", dangerous_inner_html: pictogram }
+            }
+            ol { class: "list-decimal text-left",
+                for step in instructions.steps() {
+                    li { {step.clone()} }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn ItemDetail(slug: String, sequence: String) -> Element {
+    let settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let Some(item) = settings.item(&slug, &sequence) else {
+        return rsx! { {locale.text(Text::UnknownItem)} };
+    };
+    let variants = |slugs: &[String]| {
+        slugs
+            .iter()
+            .filter_map(|slug| ItemList::find(slug))
+            .collect_vec()
+    };
+    let (easier, harder) = (variants(item.easier()), variants(item.harder()));
+    rsx! {
+        div { id: "item", class: "p-2",
+            h1 { class: "text-3xl", {locale.item(&item)} }
+            if let Some(pictogram) = item.pictogram() {
+                div { id: "pictogram", class: "w-32", dangerous_inner_html: pictogram }
+            }
+            if let Some(description) = locale.item_description(&item) {
+                p { {description} }
+            }
+            if let Some(difficulty) = item.level() {
+                p { {format!("{} {difficulty}", difficulty.icon())} }
+            }
+            p {
+                for tag in item.tags().iter() {
+                    Link {
+                        class: "p-1",
+                        to: Route::Items { slug: tag.slug() },
+                        {locale.tag(tag)}
+                    }
+                }
+            }
+            if let Some(instructions) = locale.item_instructions(&item) {
+                if let Some(setup) = instructions.setup() {
+                    h2 { {locale.text(Text::Setup)} }
+                    p { {setup} }
+                }
+                if !instructions.steps().is_empty() {
+                    h2 { {locale.text(Text::Execution)} }
+                    ol { class: "list-decimal list-inside",
+                        for step in instructions.steps() {
+                            li { {step.clone()} }
+                        }
+                    }
+                }
+                if !instructions.mistakes().is_empty() {
+                    h2 { {locale.text(Text::CommonMistakes)} }
+                    ul { class: "list-disc list-inside",
+                        for mistake in instructions.mistakes() {
+                            li { {mistake.clone()} }
+                        }
+                    }
+                }
+                if let Some(breathing) = instructions.breathing() {
+                    h2 { {locale.text(Text::Breathing)} }
+                    p { {breathing} }
+                }
+            }
+            for (title , items) in [(locale.text(Text::Easier), easier), (locale.text(Text::Harder), harder)] {
+                if !items.is_empty() {
+                    h2 { {title} }
+                    ul {
+                        for item in items {
+                            li {
+                                Link {
+                                    to: Route::ItemDetail {
+                                        slug: item.slug(),
+                                        sequence: sequence.clone(),
+                                    },
+                                    {locale.item(&item)}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Mirror of a timer broadcast to a room, with local sound control only
+#[component]
+pub fn Follower(room: String) -> Element {
+    let settings = use_context::<Settings>();
+    let locale = *settings.locale.read();
+    let sound_signal = use_signal(|| SoundSignal::from_muted(false));
+    let mut timer = use_signal(|| {
+        Timer::from_sequence(
+            &Sequence::default(),
+            &sound_signal.peek(),
+            Box::new(AudioPlayer),
+        )
+    });
+    let mut connected = use_signal(|| false);
+    let relay = settings.relay.read().clone();
+    // rejoin the room whenever the relay drops the connection
+    let _connection = use_resource(use_reactive!(|relay, room| async move {
+        loop {
+            connected.set(true);
+            sync::follow(&relay, &room, |message| match message {
+                Message::State(state) => timer.write().follow(&state),
+                Message::Ring { sound } => sound_signal.peek().ring(&sound, &AudioPlayer),
+                Message::Join { .. } => {}
+            })
+            .await;
+            connected.set(false);
+            gloo::timers::future::TimeoutFuture::new(sync::RECONNECT_DELAY).await;
+        }
+    }));
+    let mut global = use_context_provider(|| Global {
+        timer,
+        sound_signal,
+    });
+    let mut display = use_signal(|| false);
+    let timer = timer.read();
+    rsx! {
+        Sounds {}
+        div { id: "follower", class: "grid gap-4 grid-cols-1 text-3xl p-2",
+            if display() {
+                GymDisplay { display }
+            }
+            div { class: "flex items-center justify-center text-base",
+                if connected() {
+                    {format!("{} {room}", locale.text(Text::FollowingRoom))}
+                } else {
+                    {format!("{} {room}", locale.text(Text::DisconnectedFromRoom))}
+                }
+            }
+            div { class: "flex items-center justify-center",
+                span { id: "current_workout", {timer.localized_label(locale)} }
+            }
+            div { class: "flex items-center justify-center",
+                span { id: "counter", title: locale.text(Text::TimeLeft), {timer.left().to_string()} }
+            }
+            if let Some(next_workout) = timer.sequence().next_workout() {
+                div { class: "flex items-center justify-center",
+                    span { id: "next_exercise",
+                        {format!("{}: ", locale.text(Text::Next))}
+                        {locale.item_name(next_workout.item())}
+                    }
+                }
+            }
+            div { class: "flex items-center justify-evenly",
+                button {
+                    id: "toggle_signal",
+                    title: locale.text(global.sound_signal.read().state().next_text()),
+                    onclick: move |_| global.sound_signal.with_mut(SoundSignal::toggle),
+                    {global.sound_signal.read().next().to_string()}
+                }
+                button {
+                    id: "gym_display",
+                    title: locale.text(Text::GymDisplay),
+                    onclick: move |_| {
+                        display.set(true);
+                        document::eval(ENTER_DISPLAY);
+                    },
+                    "📺"
+                }
+                Link {
+                    id: "home_link",
+                    to: Route::Sequences {},
+                    {locale.text(Text::Home)}
+                }
             }
         }
     }
@@ -0,0 +1,19 @@
+use dioxus::prelude::*;
+
+/// Hand bytes to the browser as a file download
+const SAVE: &str = r#"
+    const [name, mime, bytes] = await dioxus.recv();
+    const url = URL.createObjectURL(new Blob([new Uint8Array(bytes)], { type: mime }));
+    const link = document.createElement("a");
+    link.href = url;
+    link.download = name;
+    link.click();
+    URL.revokeObjectURL(url);
+"#;
+
+pub fn save(name: &str, mime: &str, bytes: &[u8]) {
+    let eval = document::eval(SAVE);
+    if let Err(err) = eval.send((name, mime, bytes)) {
+        dioxus::logger::tracing::info!("failed to save {name}: {err:?}");
+    }
+}
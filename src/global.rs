@@ -1,5 +1,9 @@
+use crate::settings::{Settings, today};
+use crate::sync::{RelayPlayer, use_controller};
 use dioxus::prelude::*;
-use sport::defaults::{DEFAULT_INTERVAL, PREPARE, SEQUENCES};
+use sport::defaults::{DEFAULT_INTERVAL, PREPARE};
+use sport::protocol::Message;
+use sport::sequence::Sequence;
 use sport::signal::SoundSignal;
 use sport::timer;
 
@@ -10,31 +14,80 @@ pub struct Global {
 }
 
 impl Global {
-    pub fn new(muted: bool, prepare: u64, sequence: &str) -> Option<Self> {
+    #[must_use]
+    /// With a non empty room, followers of that room mirror the timer,
+    /// `slug` is the sequence or template asked for, before any adaptation
+    pub fn new(muted: bool, prepare: u64, slug: &str, sequence: &Sequence, room: &str) -> Self {
         let prepare = if prepare == 0 { PREPARE } else { prepare };
-        let sequence = SEQUENCES.iter().find(|s| s.slug() == sequence)?;
-        let sound_signal = SoundSignal::from_muted(muted);
-        let mut timer = use_signal(|| {
-            timer::Timer::new(
-                std::time::Duration::from_secs(prepare),
-                sequence,
-                &sound_signal,
-                Box::new(crate::audio::AudioPlayer),
-            )
-        });
+        let sound_signal = use_signal(|| SoundSignal::from_muted(muted));
+        let player = use_hook(RelayPlayer::default);
+        let new_timer = {
+            let player = player.clone();
+            move |sequence: &Sequence| {
+                timer::Timer::new(
+                    std::time::Duration::from_secs(prepare),
+                    sequence,
+                    &sound_signal.peek(),
+                    Box::new(player.clone()),
+                )
+            }
+        };
+        let mut timer = use_signal(|| new_timer(sequence));
+        let mut planned = use_signal(|| slug.to_string());
 
+        // the same view is reused when only the route parameters change
+        let sequence = sequence.clone();
+        let slug = slug.to_string();
+        use_effect(use_reactive!(|sequence, slug| {
+            timer.set(new_timer(&sequence));
+            planned.set(slug);
+        }));
+
+        let controller = use_controller();
+        let room = room.to_string();
+        use_effect(use_reactive!(|room| {
+            let state = timer.read().state();
+            let played = player.take();
+            if room.is_empty() {
+                return;
+            }
+            controller.send((room.clone(), Message::State(state)));
+            for sound in played {
+                controller.send((room.clone(), Message::Ring { sound }));
+            }
+        }));
+
+        let mut settings = use_context::<Settings>();
         let _tick = use_resource(move || async move {
             loop {
                 gloo::timers::future::TimeoutFuture::new(DEFAULT_INTERVAL).await;
-                if timer.write().tick() {
+                let next = timer.write().tick();
+                // finished by a tick or by skipping the last workout
+                #[allow(clippy::cast_possible_truncation)]
+                let now = (js_sys::Date::now() / 1000.0) as i64;
+                let session = timer.write().session(*settings.weight.peek(), now);
+                if let Some(session) = session {
+                    settings.history.write().push(session);
+                    // a sequence mostly skipped does not complete the day
+                    let done = timer.peek().summary().done();
+                    let mut enrollment = settings.program.peek().clone();
+                    if done
+                        && enrollment
+                            .as_mut()
+                            .is_some_and(|enrollment| enrollment.record(today(), &planned.peek()))
+                    {
+                        settings.program.set(enrollment);
+                    }
+                }
+                if next {
                     gloo::timers::future::TimeoutFuture::new(DEFAULT_INTERVAL).await;
                 }
             }
         });
 
-        Some(Self {
-            sound_signal: use_signal(|| sound_signal),
+        Self {
             timer,
-        })
+            sound_signal,
+        }
     }
 }
@@ -1,8 +1,13 @@
 #![allow(non_snake_case)]
 pub mod audio;
 pub mod components;
+pub mod download;
 pub mod global;
+pub mod pwa;
 pub mod routes;
+pub mod settings;
+pub mod storage;
+pub mod sync;
 
 use dioxus::logger::tracing::Level;
 use dioxus::prelude::*;
@@ -28,11 +33,21 @@ fn main() {
     launch(App);
 }
 
+const FAVICON: Asset = asset!("/assets/favicon.png");
+const TAILWIND: Asset = asset!("/assets/tailwind.css");
+const APP_CSS: Asset = asset!("/assets/app.css");
+
 fn App() -> Element {
+    use_context_provider(settings::Settings::load);
+    use_effect(|| {
+        pwa::register(&[FAVICON, TAILWIND, APP_CSS, audio::BELL, audio::BEEP]);
+    });
     rsx! {
-        document::Link { rel: "icon", href: asset!("/assets/favicon.png") }
-        document::Stylesheet { href: asset!("/assets/tailwind.css") }
-        document::Stylesheet { href: asset!("/assets/app.css") }
+        document::Link { rel: "icon", href: FAVICON }
+        document::Link { rel: "manifest", href: "/manifest.json" }
+        document::Meta { name: "theme-color", content: "#1d232a" }
+        document::Stylesheet { href: TAILWIND }
+        document::Stylesheet { href: APP_CSS }
         Router::<routes::Route> {}
     }
 }
@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+
+/// Register the service worker then hand it the hashed urls to precache,
+/// the wasm bundle and its glue are found in the page itself.
+const REGISTER: &str = r#"
+    const assets = await dioxus.recv();
+    if (!("serviceWorker" in navigator)) {
+        return;
+    }
+    const registration = await navigator.serviceWorker.register("/sw.js");
+    const ready = await navigator.serviceWorker.ready;
+    const bundle = Array.from(document.querySelectorAll("script[src], link[rel=preload][href]"))
+        .map((element) => element.src || element.href);
+    const precache = [...new Set([...assets, ...bundle])]
+        .map((url) => new URL(url, location.href))
+        .filter((url) => url.origin === location.origin)
+        .map((url) => url.pathname);
+    (ready.active || registration.active).postMessage({ precache });
+"#;
+
+pub fn register(assets: &[Asset]) {
+    let assets = assets.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let eval = document::eval(REGISTER);
+    if let Err(err) = eval.send(assets) {
+        dioxus::logger::tracing::info!("failed to register service worker: {err:?}");
+    }
+}
@@ -1,5 +1,9 @@
-use crate::components::{Items, SequenceTimer, Sequences, SequencesJson, Tags, Workouts};
+use crate::components::{
+    Follower, History, ItemDetail, Items, Programs, SequenceTimer, Sequences, SequencesJson, Tags,
+    Today, Workouts,
+};
 use dioxus::prelude::*;
+use sport::template::{ParameterKind, Values};
 
 #[derive(Clone, Routable, Debug, PartialEq)]
 pub enum Route {
@@ -7,12 +11,118 @@ pub enum Route {
     Sequences {},
     #[route("/sequences.json")]
     SequencesJson {},
-    #[route("/sequence?:slug")]
-    SequenceTimer { slug: String },
+    #[route("/sequence?:slug&:seed&:level&:minutes&:rounds&:work&:rest&:room")]
+    SequenceTimer {
+        slug: String,
+        seed: String,
+        level: i8,
+        minutes: u64,
+        rounds: u64,
+        work: u64,
+        rest: u64,
+        room: String,
+    },
     #[route("/tags")]
     Tags {},
     #[route("/items?:slug")]
     Items { slug: String },
-    #[route("/timer?:slug")]
-    Workouts { slug: String },
+    /// Items outside the catalog are found in the sequence
+    #[route("/item?:slug&:sequence")]
+    ItemDetail { slug: String, sequence: String },
+    /// Exercises of a timer, with the same parameters
+    #[route("/timer?:slug&:seed&:level&:minutes&:rounds&:work&:rest")]
+    Workouts {
+        slug: String,
+        seed: String,
+        level: i8,
+        minutes: u64,
+        rounds: u64,
+        work: u64,
+        rest: u64,
+    },
+    #[route("/history")]
+    History {},
+    #[route("/follow?:room")]
+    Follower { room: String },
+    #[route("/programs")]
+    Programs {},
+    #[route("/today")]
+    Today {},
+}
+
+impl Route {
+    /// Timer for a sequence or a template with its default parameters
+    #[must_use]
+    pub fn timer(slug: String) -> Self {
+        Self::template(slug, &Values::new())
+    }
+    /// Timer for a template with the given parameters
+    #[must_use]
+    pub fn template(slug: String, values: &Values) -> Self {
+        let value = |kind| values.get(&kind).copied().unwrap_or_default();
+        Self::SequenceTimer {
+            slug,
+            seed: String::new(),
+            level: 0,
+            minutes: 0,
+            rounds: value(ParameterKind::Rounds),
+            work: value(ParameterKind::Work),
+            rest: value(ParameterKind::Rest),
+            room: String::new(),
+        }
+    }
+    #[must_use]
+    pub fn with_seed(mut self, value: String) -> Self {
+        if let Self::SequenceTimer { seed, .. } = &mut self {
+            *seed = value;
+        }
+        self
+    }
+    #[must_use]
+    pub fn with_level(mut self, value: i8) -> Self {
+        if let Self::SequenceTimer { level, .. } = &mut self {
+            *level = value;
+        }
+        self
+    }
+    #[must_use]
+    pub fn with_minutes(mut self, value: u64) -> Self {
+        if let Self::SequenceTimer { minutes, .. } = &mut self {
+            *minutes = value;
+        }
+        self
+    }
+    /// Exercises of this timer, as planned by its parameters
+    #[must_use]
+    pub fn workouts(&self) -> Self {
+        match self.clone() {
+            Self::SequenceTimer {
+                slug,
+                seed,
+                level,
+                minutes,
+                rounds,
+                work,
+                rest,
+                ..
+            } => Self::Workouts {
+                slug,
+                seed,
+                level,
+                minutes,
+                rounds,
+                work,
+                rest,
+            },
+            route => route,
+        }
+    }
+    /// Broadcast the timer to the followers of a room
+    #[must_use]
+    pub fn with_room(mut self, value: String) -> Self {
+        if let Self::SequenceTimer { room, .. } = &mut self {
+            *room = value;
+        }
+        self
+    }
 }
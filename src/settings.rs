@@ -0,0 +1,155 @@
+use crate::storage::{
+    EQUIPMENT_KEY, HISTORY_KEY, KEYS_KEY, LOCALE_KEY, PROGRAM_KEY, RELAY_KEY, SEQUENCES_KEY,
+    WEIGHT_KEY, load, save,
+};
+use dioxus::prelude::*;
+use sport::defaults::{DEFAULT_WEIGHT, DURATION_CHOICES};
+use sport::equipment::EquipmentProfile;
+use sport::i18n::Locale;
+use sport::item::Item;
+use sport::item_list::ItemList;
+use sport::keys::KeyBindings;
+use sport::program::Enrollment;
+use sport::sequence::Sequence;
+use sport::session::History;
+use sport::template::Values;
+
+#[derive(Clone, Copy)]
+pub struct Settings {
+    pub equipment: Signal<EquipmentProfile>,
+    /// Body weight in kilograms, for calorie estimations
+    pub weight: Signal<f64>,
+    pub history: Signal<History>,
+    pub locale: Signal<Locale>,
+    pub keys: Signal<KeyBindings>,
+    /// WebSocket url of the relay used to mirror a timer on other devices
+    pub relay: Signal<String>,
+    /// Program being followed, if any
+    pub program: Signal<Option<Enrollment>>,
+    /// Sequences imported from spreadsheets
+    pub sequences: Signal<Vec<Sequence>>,
+}
+
+impl Settings {
+    #[must_use]
+    pub fn load() -> Self {
+        let equipment = use_signal(|| load(EQUIPMENT_KEY).unwrap_or_else(EquipmentProfile::all));
+        use_effect(move || save(EQUIPMENT_KEY, &*equipment.read()));
+        let weight = use_signal(|| load(WEIGHT_KEY).unwrap_or(DEFAULT_WEIGHT));
+        use_effect(move || save(WEIGHT_KEY, &*weight.read()));
+        let history = use_signal(|| load(HISTORY_KEY).unwrap_or_default());
+        use_effect(move || save(HISTORY_KEY, &*history.read()));
+        let locale = use_signal(|| load(LOCALE_KEY).unwrap_or_else(browser_locale));
+        use_effect(move || save(LOCALE_KEY, &*locale.read()));
+        let keys = use_signal(|| load(KEYS_KEY).unwrap_or_default());
+        use_effect(move || save(KEYS_KEY, &*keys.read()));
+        let relay = use_signal(|| load(RELAY_KEY).unwrap_or_else(crate::sync::default_relay));
+        use_effect(move || save(RELAY_KEY, &*relay.read()));
+        let program = use_signal(|| load(PROGRAM_KEY).unwrap_or_default());
+        use_effect(move || save(PROGRAM_KEY, &*program.read()));
+        let sequences = use_signal(|| load(SEQUENCES_KEY).unwrap_or_default());
+        use_effect(move || save(SEQUENCES_KEY, &*sequences.read()));
+        Self {
+            equipment,
+            weight,
+            history,
+            locale,
+            keys,
+            relay,
+            program,
+            sequences,
+        }
+    }
+    /// Imported sequences first, then the defaults and templates
+    #[must_use]
+    pub fn find(&self, slug: &str, values: &Values) -> Option<Sequence> {
+        self.sequences
+            .read()
+            .iter()
+            .find(|sequence| sequence.slug() == slug)
+            .cloned()
+            .or_else(|| sport::defaults::find(slug, values))
+    }
+    /// Sequence as the timer runs it, with its level, duration, equipment and seed
+    #[must_use]
+    pub fn plan(
+        &self,
+        slug: &str,
+        values: &Values,
+        level: i8,
+        minutes: u64,
+        seed: &str,
+    ) -> Option<Sequence> {
+        let mut sequence = self.find(slug, values)?.progress(level);
+        // from the URL, bounded by the longest choice offered
+        let longest = DURATION_CHOICES.iter().copied().max().unwrap_or_default();
+        let minutes = minutes.min(longest);
+        if minutes > 0 {
+            sequence = sequence.fit(std::time::Duration::from_secs(minutes * 60));
+        }
+        let mut sequence = self.equipment.read().adapt(&sequence).unwrap_or(sequence);
+        if let Ok(seed) = seed.parse::<u64>() {
+            sequence.shuffle_with_seed(seed);
+        }
+        Some(sequence)
+    }
+    /// Catalog item, or an item of the sequence like a combo or an imported one
+    #[must_use]
+    pub fn item(&self, slug: &str, sequence: &str) -> Option<Item> {
+        ItemList::find(slug).or_else(|| {
+            self.find(sequence, &Values::new())?
+                .unique_items()
+                .into_iter()
+                .find(|item| item.slug() == slug)
+                .cloned()
+        })
+    }
+    /// The sequence listed after `slug`, imported ones first, wrapping around
+    #[must_use]
+    pub fn next(&self, slug: &str) -> Option<Sequence> {
+        let sequences = self
+            .sequences
+            .read()
+            .iter()
+            .chain(sport::defaults::SEQUENCES.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let position = sequences
+            .iter()
+            .position(|sequence| sequence.slug() == slug)?;
+        sequences.into_iter().cycle().nth(position + 1)
+    }
+    /// Replace an imported sequence of the same slug
+    pub fn import(&mut self, sequence: Sequence) {
+        let mut sequences = self.sequences.write();
+        sequences.retain(|imported| imported.slug() != sequence.slug());
+        sequences.push(sequence);
+    }
+    /// Forget an imported sequence, defaults stay
+    pub fn remove(&mut self, slug: &str) {
+        self.sequences
+            .write()
+            .retain(|imported| imported.slug() != slug);
+    }
+}
+
+fn browser_locale() -> Locale {
+    web_sys::window()
+        .and_then(|window| window.navigator().language())
+        .map(|language| Locale::from_language(&language))
+        .unwrap_or_default()
+}
+
+/// Local calendar date of the browser
+#[must_use]
+pub fn today() -> time::Date {
+    let now = js_sys::Date::new_0();
+    let month = u8::try_from(now.get_month() + 1)
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok())
+        .unwrap_or(time::Month::January);
+    let day = u8::try_from(now.get_date()).unwrap_or(1);
+    #[allow(clippy::cast_possible_wrap)]
+    let year = now.get_full_year() as i32;
+    time::Date::from_calendar_date(year, month, day).unwrap_or(time::Date::MIN)
+}
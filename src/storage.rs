@@ -0,0 +1,24 @@
+use dioxus::logger::tracing::info;
+use gloo::storage::{LocalStorage, Storage};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+pub const EQUIPMENT_KEY: &str = "equipment";
+pub const WEIGHT_KEY: &str = "weight";
+pub const HISTORY_KEY: &str = "history";
+pub const LOCALE_KEY: &str = "locale";
+pub const KEYS_KEY: &str = "keys";
+pub const RELAY_KEY: &str = "relay";
+pub const PROGRAM_KEY: &str = "program";
+pub const SEQUENCES_KEY: &str = "sequences";
+
+#[must_use]
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    LocalStorage::get(key).ok()
+}
+
+pub fn save<T: Serialize>(key: &str, value: &T) {
+    if let Err(err) = LocalStorage::set(key, value) {
+        info!("failed to save {key}: {err:?}");
+    }
+}
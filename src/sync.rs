@@ -0,0 +1,117 @@
+use crate::audio::AudioPlayer;
+use crate::settings::Settings;
+use dioxus::logger::tracing::info;
+use dioxus::prelude::*;
+use futures_util::stream::SplitSink;
+use futures_util::{SinkExt, StreamExt};
+use gloo::net::websocket::Message as Frame;
+use gloo::net::websocket::futures::WebSocket;
+use sport::errors::ErrorKind;
+use sport::player::Player;
+use sport::protocol::{Message, Role};
+use sport::sound::Sound;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub const RELAY_PORT: u16 = 9001;
+/// Milliseconds before a follower joins its room again
+pub const RECONNECT_DELAY: u32 = 2000;
+
+/// Relay on the host serving the application, usually the laptop of the coach
+#[must_use]
+pub fn default_relay() -> String {
+    let host = web_sys::window()
+        .and_then(|window| window.location().hostname().ok())
+        .filter(|host| !host.is_empty())
+        .unwrap_or_else(|| "localhost".to_string());
+    format!("ws://{host}:{RELAY_PORT}")
+}
+
+/// Plays sounds locally and keeps them to forward them to the followers
+#[derive(Clone, Default)]
+pub struct RelayPlayer {
+    played: Rc<RefCell<Vec<Sound>>>,
+}
+
+impl RelayPlayer {
+    #[must_use]
+    pub fn take(&self) -> Vec<Sound> {
+        self.played.take()
+    }
+}
+
+impl Player for RelayPlayer {
+    fn play(&self, sound: &Sound) -> Result<(), ErrorKind> {
+        self.played.borrow_mut().push(sound.clone());
+        AudioPlayer.play(sound)
+    }
+    fn muted(&self, sound: &Sound) -> Option<Result<bool, ErrorKind>> {
+        AudioPlayer.muted(sound)
+    }
+    fn unmute(&self, sound: &Sound) -> Result<(), ErrorKind> {
+        AudioPlayer.unmute(sound)
+    }
+}
+
+async fn join(relay: &str, room: &str, role: Role) -> Option<WebSocket> {
+    let mut websocket = WebSocket::open(relay)
+        .inspect_err(|err| info!("cannot open relay {relay}: {err:?}"))
+        .ok()?;
+    let join = Message::Join {
+        room: room.to_string(),
+        role,
+    };
+    websocket
+        .send(Frame::Text(join.to_json()))
+        .await
+        .inspect_err(|err| info!("cannot join room {room}: {err:?}"))
+        .ok()?;
+    Some(websocket)
+}
+
+/// A message for the followers of a room
+pub type Outgoing = (String, Message);
+
+/// Keep one connection to the relay for the current room, the room can change at any time
+#[must_use]
+pub fn use_controller() -> Coroutine<Outgoing> {
+    let settings = use_context::<Settings>();
+    use_coroutine(
+        move |mut outgoing: UnboundedReceiver<Outgoing>| async move {
+            let mut connection: Option<(String, SplitSink<WebSocket, Frame>)> = None;
+            while let Some((room, message)) = outgoing.next().await {
+                if connection
+                    .as_ref()
+                    .is_none_or(|(connected, _)| *connected != room)
+                {
+                    let relay = settings.relay.peek().clone();
+                    connection = join(&relay, &room, Role::Controller)
+                        .await
+                        .map(|websocket| (room.clone(), websocket.split().0));
+                }
+                if let Some((_, sink)) = connection.as_mut()
+                    && sink.send(Frame::Text(message.to_json())).await.is_err()
+                {
+                    connection = None;
+                }
+            }
+        },
+    )
+}
+
+/// Receive the controller messages of a room until the connection closes
+pub async fn follow(relay: &str, room: &str, mut on_message: impl FnMut(Message)) {
+    let Some(websocket) = join(relay, room, Role::Follower).await else {
+        return;
+    };
+    let (_sink, mut stream) = websocket.split();
+    while let Some(Ok(frame)) = stream.next().await {
+        if let Frame::Text(text) = frame {
+            match Message::from_json(&text) {
+                Ok(message) => on_message(message),
+                Err(err) => info!("invalid message from relay: {err}"),
+            }
+        }
+    }
+    info!("relay closed the connection to room {room}");
+}
@@ -0,0 +1,54 @@
+// Generated by build.rs from sw.template.js, do not edit
+const VERSION = "boxingtimer-{{VERSION}}";
+const SHELL = ["/", "/manifest.json", "/icon-512.png"];
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches.open(VERSION).then((cache) => cache.addAll(SHELL)).then(() => self.skipWaiting())
+  );
+});
+
+// a new build has a new version: drop the caches of the previous ones
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) => Promise.all(keys.filter((key) => key !== VERSION).map((key) => caches.delete(key))))
+      .then(() => self.clients.claim())
+  );
+});
+
+// the page sends the hashed urls of its bundle, styles and sounds
+self.addEventListener("message", (event) => {
+  if (event.data && Array.isArray(event.data.precache)) {
+    event.waitUntil(caches.open(VERSION).then((cache) => cache.addAll(event.data.precache)));
+  }
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  if (request.method !== "GET" || new URL(request.url).origin !== self.location.origin) {
+    return;
+  }
+  event.respondWith(
+    caches.open(VERSION).then(async (cache) => {
+      const cached = await cache.match(request, { ignoreSearch: request.mode === "navigate" });
+      if (cached) {
+        return cached;
+      }
+      try {
+        const response = await fetch(request);
+        if (response.ok) {
+          cache.put(request, response.clone());
+        }
+        return response;
+      } catch (error) {
+        // every route is rendered by the application shell
+        if (request.mode === "navigate") {
+          return cache.match("/");
+        }
+        throw error;
+      }
+    })
+  );
+});